@@ -0,0 +1,102 @@
+use std::fmt::{self, Display};
+
+/// A puzzle answer of any kind. Every integer type converts to
+/// [`Answer::Number`], so answers compare the same whichever type a solver
+/// happened to compute them in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! from_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(n: $ty) -> Self {
+                    Answer::Number(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+/// Values above `i128::MAX` have no [`Answer::Number`], so they become text.
+/// They display the same either way.
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        match i128::try_from(n) {
+            Ok(n) => Answer::Number(n),
+            Err(_) => Answer::Text(n.to_string()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// Compares against an expected answer written down as text, such as a line
+/// of a fixture or of the answers file. Numbers match by value, so `042`
+/// matches 42; text must match exactly.
+impl PartialEq<str> for Answer {
+    fn eq(&self, expected: &str) -> bool {
+        match self {
+            Answer::Number(n) => expected.trim().parse::<i128>() == Ok(*n),
+            Answer::Text(s) => s == expected,
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, expected: &&str) -> bool {
+        self == *expected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from() {
+        assert_eq!(Answer::from(357_u64), Answer::Number(357));
+        assert_eq!(Answer::from(357_usize), Answer::from(357_i64));
+        assert_eq!(Answer::from(-4_i64).to_string(), "-4");
+        assert_eq!(Answer::from(u64::MAX).to_string(), u64::MAX.to_string());
+        assert_eq!(Answer::from(7_u128), Answer::Number(7));
+        assert_eq!(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()));
+        assert_eq!(Answer::from(u128::MAX), u128::MAX.to_string().as_str());
+        assert_eq!(Answer::from("ABC"), Answer::Text("ABC".to_string()));
+        assert_eq!(Answer::from("ABC".to_string()).to_string(), "ABC");
+    }
+
+    #[test]
+    fn test_eq_expected() {
+        assert_eq!(Answer::from(42_u64), "42");
+        assert_eq!(Answer::from(42_u64), "042");
+        assert_ne!(Answer::from(42_u64), "43");
+        assert_ne!(Answer::from(42_u64), "forty-two");
+        assert_eq!(Answer::from("0123"), "0123");
+        assert_ne!(Answer::from("0123"), "123");
+    }
+}
@@ -0,0 +1,235 @@
+use std::{
+    fmt::{self, Display},
+    fs, io,
+    path::Path,
+};
+
+use crate::{Part, PartAnswer, Solution, Solver, day::Day, input};
+
+/// File in the inputs directory holding known answers.
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// A confirmed answer for one part of a day, optionally tied to the input it
+/// was computed from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KnownAnswer {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub input_hash: Option<String>,
+}
+
+/// Registry of known answers, stored one per line as
+/// `<day> <part> <answer> [input hash]`. Blank lines and lines starting with
+/// `#` are ignored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    entries: Vec<KnownAnswer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected } => write!(f, "FAIL (expected {})", expected),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+fn parse_line(line: &str) -> Result<KnownAnswer, String> {
+    let fields = line.split_whitespace().collect::<Vec<_>>();
+    let (day, part, answer, input_hash) = match fields[..] {
+        [day, part, answer] => (day, part, answer, None),
+        [day, part, answer, hash] => (day, part, answer, Some(hash.to_string())),
+        _ => return Err("expected `<day> <part> <answer> [input hash]`".to_string()),
+    };
+
+    Ok(KnownAnswer {
+        day: day.parse().map_err(|_| format!("invalid day `{}`", day))?,
+        part: part.parse()?,
+        answer: answer.to_string(),
+        input_hash,
+    })
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let entries = text
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| (idx, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+            .map(|(idx, line)| parse_line(line).map_err(|e| format!("line {}: {}", idx + 1, e)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Answers { entries })
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let text = fs::read_to_string(path)?;
+        Self::parse(&text).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), e),
+            )
+        })
+    }
+
+    /// Loads `inputs/answers.txt` if there is one, otherwise an empty registry.
+    pub fn load_default() -> io::Result<Self> {
+        match input::locate(ANSWERS_FILE) {
+            Some(path) => Self::load(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    pub fn entries(&self) -> &[KnownAnswer] {
+        &self.entries
+    }
+
+    /// Finds the expected answer for an input. An entry recorded for this
+    /// exact input wins over one recorded without a hash; entries for other
+    /// inputs never match.
+    pub fn lookup(&self, day: u8, part: Part, input_hash: &str) -> Option<&str> {
+        let candidates = || {
+            self.entries
+                .iter()
+                .filter(move |entry| entry.day == day && entry.part == part)
+        };
+
+        candidates()
+            .find(|entry| entry.input_hash.as_deref() == Some(input_hash))
+            .or_else(|| candidates().find(|entry| entry.input_hash.is_none()))
+            .map(|entry| entry.answer.as_str())
+    }
+
+    pub fn check(&self, answer: &PartAnswer, input_hash: &str) -> Verdict {
+        match self.lookup(answer.day, answer.part, input_hash) {
+            Some(expected) if answer.answer == expected => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+/// Checks a day against its real input and the known answers, for use in a
+/// day's tests. Only parts with a known answer are solved. Panics when the
+/// input or its answers are missing, so the test is ignored by default and
+/// run with `cargo test -- --ignored` on machines that have them.
+pub fn assert_known<S: Solution>() {
+    let source = S::EMBEDDED_INPUT
+        .map(input::InputSource::Embedded)
+        .or_else(|| input::default_path(S::DAY).map(input::InputSource::Path))
+        .unwrap_or_else(|| panic!("no input for day {:02}", S::DAY));
+
+    let input = source.read().expect("input should be readable");
+    let answers = Answers::load_default().expect("answers should be valid");
+    let hash = input::hash(&input);
+
+    let parts = Part::ALL
+        .into_iter()
+        .filter(|&part| answers.lookup(S::DAY, part, &hash).is_some())
+        .collect::<Vec<_>>();
+    assert!(
+        !parts.is_empty(),
+        "no known answers for day {:02} in {}",
+        S::DAY,
+        ANSWERS_FILE
+    );
+
+    let solver = Solver::<S>::new();
+    let answers_given = solver
+        .solve(&input, &parts)
+        .unwrap_or_else(|e| panic!("{}", solver.render_error(&e, &input)));
+
+    for answer in answers_given {
+        if let Verdict::Fail { expected } = answers.check(&answer, &hash) {
+            panic!("{}, expected {}", answer, expected);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    const ANSWERS: &str = "
+# day part answer [input hash]
+01 1 1076
+01 2 6379
+01 2 42 cbf29ce484222325
+7 1 21 0000000000000000
+";
+
+    fn answer(day: u8, part: Part, answer: u64) -> PartAnswer {
+        PartAnswer {
+            day,
+            part,
+            answer: answer.into(),
+            answer_type: "u64",
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.entries().len(), 4);
+        assert_eq!(
+            answers.entries()[2],
+            KnownAnswer {
+                day: 1,
+                part: Part::Two,
+                answer: "42".to_string(),
+                input_hash: Some("cbf29ce484222325".to_string()),
+            }
+        );
+
+        assert_eq!(
+            Answers::parse("01 1 2\n01 3 4"),
+            Err("line 2: invalid part `3`, expected 1 or 2".to_string())
+        );
+        assert!(Answers::parse("01 1").is_err());
+    }
+
+    #[test]
+    fn test_lookup() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.lookup(1, Part::One, "abc"), Some("1076"));
+        assert_eq!(answers.lookup(1, Part::Two, "abc"), Some("6379"));
+        assert_eq!(answers.lookup(1, Part::Two, "cbf29ce484222325"), Some("42"));
+        assert_eq!(answers.lookup(7, Part::One, "abc"), None);
+        assert_eq!(answers.lookup(3, Part::One, "abc"), None);
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(
+            answers.check(&answer(1, Part::One, 1076), "abc"),
+            Verdict::Pass
+        );
+        assert_eq!(
+            answers.check(&answer(1, Part::One, 1075), "abc"),
+            Verdict::Fail {
+                expected: "1076".to_string()
+            }
+        );
+        assert_eq!(
+            answers.check(&answer(2, Part::One, 1), "abc"),
+            Verdict::Unknown
+        );
+    }
+}
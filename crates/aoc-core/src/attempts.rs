@@ -0,0 +1,358 @@
+use std::{
+    fmt::{self, Display},
+    fs, io,
+    io::Write,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::{Answer, Part, PartAnswer, input};
+
+/// File in the inputs directory logging submitted answers.
+pub const ATTEMPTS_FILE: &str = "attempts.txt";
+
+/// What the puzzle site said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Rejected without saying which way.
+    Wrong,
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too-high"),
+            Outcome::TooLow => write!(f, "too-low"),
+            Outcome::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Outcome::Correct),
+            "too-high" => Ok(Outcome::TooHigh),
+            "too-low" => Ok(Outcome::TooLow),
+            "wrong" => Ok(Outcome::Wrong),
+            _ => Err(format!(
+                "invalid outcome `{}`, expected correct, too-high, too-low or wrong",
+                s
+            )),
+        }
+    }
+}
+
+/// One submitted answer and its outcome, optionally tied to the input it was
+/// computed from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub outcome: Outcome,
+    pub input_hash: Option<String>,
+}
+
+impl Display for Attempt {
+    /// Renders the attempt as a line of the log.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02} {} {} {}",
+            self.day, self.part, self.answer, self.outcome
+        )?;
+        if let Some(hash) = &self.input_hash {
+            write!(f, " {}", hash)?;
+        }
+        Ok(())
+    }
+}
+
+/// Reason to doubt a freshly computed answer, judging by earlier attempts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Warning {
+    /// Another answer was already accepted.
+    Differs { correct: String },
+    /// This exact answer was already submitted and rejected.
+    Rejected(Outcome),
+    /// The answer is no lower than one that was too high.
+    TooHigh { bound: String },
+    /// The answer is no higher than one that was too low.
+    TooLow { bound: String },
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Warning::Differs { correct } => {
+                write!(f, "differs from {}, which was accepted", correct)
+            }
+            Warning::Rejected(Outcome::TooHigh) => write!(f, "was already rejected as too high"),
+            Warning::Rejected(Outcome::TooLow) => write!(f, "was already rejected as too low"),
+            Warning::Rejected(_) => write!(f, "was already rejected"),
+            Warning::TooHigh { bound } => write!(f, "is not below {}, which was too high", bound),
+            Warning::TooLow { bound } => write!(f, "is not above {}, which was too low", bound),
+        }
+    }
+}
+
+fn parse_line(line: &str) -> Result<Attempt, String> {
+    let fields = line.split_whitespace().collect::<Vec<_>>();
+    let (day, part, answer, outcome, input_hash) = match fields[..] {
+        [day, part, answer, outcome] => (day, part, answer, outcome, None),
+        [day, part, answer, outcome, hash] => (day, part, answer, outcome, Some(hash.to_string())),
+        _ => return Err("expected `<day> <part> <answer> <outcome> [input hash]`".to_string()),
+    };
+
+    Ok(Attempt {
+        day: day.parse().map_err(|_| format!("invalid day `{}`", day))?,
+        part: part.parse()?,
+        answer: answer.to_string(),
+        outcome: outcome.parse()?,
+        input_hash,
+    })
+}
+
+/// Log of submitted answers, stored one per line as
+/// `<day> <part> <answer> <outcome> [input hash]`, oldest first. Blank lines
+/// and lines starting with `#` are ignored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Attempts {
+    entries: Vec<Attempt>,
+}
+
+impl Attempts {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let entries = text
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| (idx, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+            .map(|(idx, line)| parse_line(line).map_err(|e| format!("line {}: {}", idx + 1, e)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Attempts { entries })
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let text = fs::read_to_string(path)?;
+        Self::parse(&text).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), e),
+            )
+        })
+    }
+
+    /// `inputs/attempts.txt`, wherever the inputs directory is found, or
+    /// relative to the current directory if there is no log yet.
+    pub fn default_path() -> PathBuf {
+        input::locate(ATTEMPTS_FILE)
+            .unwrap_or_else(|| Path::new(input::INPUTS_DIR).join(ATTEMPTS_FILE))
+    }
+
+    /// Loads `inputs/attempts.txt` if there is one, otherwise an empty log.
+    pub fn load_default() -> io::Result<Self> {
+        match input::locate(ATTEMPTS_FILE) {
+            Some(path) => Self::load(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Appends one attempt to the log at `path`, creating it and its
+    /// directory if needed.
+    pub fn append(path: &Path, attempt: &Attempt) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        writeln!(file, "{}", attempt)
+    }
+
+    pub fn entries(&self) -> &[Attempt] {
+        &self.entries
+    }
+
+    /// Attempts for one part, skipping those recorded for another input.
+    pub fn for_part(
+        &self,
+        day: u8,
+        part: Part,
+        input_hash: &str,
+    ) -> impl Iterator<Item = &Attempt> {
+        self.entries.iter().filter(move |attempt| {
+            attempt.day == day
+                && attempt.part == part
+                && attempt
+                    .input_hash
+                    .as_deref()
+                    .is_none_or(|hash| hash == input_hash)
+        })
+    }
+
+    /// Checks a computed answer against earlier attempts. Bounds from
+    /// too-high and too-low attempts only apply to numeric answers.
+    pub fn check(&self, answer: &PartAnswer, input_hash: &str) -> Option<Warning> {
+        let attempts = self
+            .for_part(answer.day, answer.part, input_hash)
+            .collect::<Vec<_>>();
+
+        // An answer accepted for this exact input wins over one without a hash
+        let correct = attempts
+            .iter()
+            .filter(|a| a.outcome == Outcome::Correct)
+            .max_by_key(|a| a.input_hash.is_some());
+        if let Some(correct) = correct {
+            return (answer.answer != correct.answer.as_str()).then(|| Warning::Differs {
+                correct: correct.answer.clone(),
+            });
+        }
+
+        if let Some(rejected) = attempts.iter().find(|a| answer.answer == a.answer.as_str()) {
+            return Some(Warning::Rejected(rejected.outcome));
+        }
+
+        let Answer::Number(value) = answer.answer else {
+            return None;
+        };
+        let bound = |outcome: Outcome| {
+            attempts
+                .iter()
+                .filter(move |a| a.outcome == outcome)
+                .filter_map(|a| Some((a.answer.parse::<i128>().ok()?, &a.answer)))
+        };
+
+        if let Some((_, high)) = bound(Outcome::TooHigh)
+            .min()
+            .filter(|&(high, _)| value >= high)
+        {
+            return Some(Warning::TooHigh {
+                bound: high.clone(),
+            });
+        }
+        if let Some((_, low)) = bound(Outcome::TooLow)
+            .max()
+            .filter(|&(low, _)| value <= low)
+        {
+            return Some(Warning::TooLow { bound: low.clone() });
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    const ATTEMPTS: &str = "
+# day part answer outcome [input hash]
+01 1 2000 too-high
+01 1 900 too-low
+01 1 1234 wrong
+01 1 1500 too-high
+01 2 6379 correct
+01 2 77 correct 0000000000000000
+";
+
+    fn answer(day: u8, part: Part, answer: impl Into<Answer>) -> PartAnswer {
+        PartAnswer {
+            day,
+            part,
+            answer: answer.into(),
+            answer_type: "u64",
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let attempts = Attempts::parse(ATTEMPTS).unwrap();
+        assert_eq!(attempts.entries().len(), 6);
+        assert_eq!(
+            attempts.entries()[5],
+            Attempt {
+                day: 1,
+                part: Part::Two,
+                answer: "77".to_string(),
+                outcome: Outcome::Correct,
+                input_hash: Some("0000000000000000".to_string()),
+            }
+        );
+        assert_eq!(
+            attempts.entries()[5].to_string(),
+            "01 2 77 correct 0000000000000000"
+        );
+        assert_eq!(attempts.entries()[0].to_string(), "01 1 2000 too-high");
+
+        assert_eq!(
+            Attempts::parse("01 1 2 high"),
+            Err(
+                "line 1: invalid outcome `high`, expected correct, too-high, too-low or wrong"
+                    .to_string()
+            )
+        );
+        assert!(Attempts::parse("01 1 2").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let attempts = Attempts::parse(ATTEMPTS).unwrap();
+        let check = |part, given: u64| attempts.check(&answer(1, part, given), "abc");
+
+        assert_eq!(check(Part::One, 1000), None);
+        assert_eq!(
+            check(Part::One, 1234),
+            Some(Warning::Rejected(Outcome::Wrong))
+        );
+        assert_eq!(
+            check(Part::One, 2000),
+            Some(Warning::Rejected(Outcome::TooHigh))
+        );
+        assert_eq!(
+            check(Part::One, 1600),
+            Some(Warning::TooHigh {
+                bound: "1500".to_string()
+            })
+        );
+        assert_eq!(
+            check(Part::One, 12),
+            Some(Warning::TooLow {
+                bound: "900".to_string()
+            })
+        );
+        assert_eq!(attempts.check(&answer(1, Part::One, "abc"), "abc"), None);
+
+        assert_eq!(check(Part::Two, 6379), None);
+        assert_eq!(
+            check(Part::Two, 6380),
+            Some(Warning::Differs {
+                correct: "6379".to_string()
+            })
+        );
+        assert_eq!(
+            attempts.check(&answer(1, Part::Two, 77_u64), "0000000000000000"),
+            None
+        );
+        assert_eq!(attempts.check(&answer(2, Part::One, 1_u64), "abc"), None);
+
+        assert_eq!(
+            Warning::TooHigh {
+                bound: "1500".to_string()
+            }
+            .to_string(),
+            "is not below 1500, which was too high"
+        );
+    }
+}
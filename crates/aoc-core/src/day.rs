@@ -0,0 +1,233 @@
+use std::{
+    any,
+    fmt::{self, Display},
+    marker::PhantomData,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{Answer, Error, Result, Rng, Solution, Trace};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part `{}`, expected 1 or 2", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartAnswer {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Answer,
+    /// Rust type the solver returned the answer as, e.g. `u64`.
+    pub answer_type: &'static str,
+    /// Time spent solving this part, excluding parsing.
+    pub elapsed: Duration,
+}
+
+impl Display for PartAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {:02} part {}: {}", self.day, self.part, self.answer)
+    }
+}
+
+/// Object-safe view of a [`Solution`], so tooling can hold every day in one
+/// list regardless of its model and answer types.
+pub trait Day: Sync {
+    fn day(&self) -> u8;
+
+    fn embedded_input(&self) -> Option<&'static str>;
+
+    /// Normalizes and parses `input` once, then solves the requested parts in
+    /// order, timing each step.
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Run>;
+
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<PartAnswer>> {
+        Ok(self.run(input, parts)?.answers)
+    }
+
+    /// Random valid input from `seed`, if the day has a generator.
+    fn generate(&self, seed: u64, size: usize) -> Option<String>;
+
+    /// Answer of the day's reference implementation, if it has one.
+    fn reference(&self, input: &str, part: Part) -> Option<Answer>;
+
+    /// Normalizes and parses `input`, then traces the solver over it.
+    /// `Ok(None)` if the day has no trace.
+    fn trace(&self, input: &str) -> Result<Option<Trace>>;
+
+    /// Renders an error from this day against the normalized input its
+    /// positions refer to, rather than the raw `input`.
+    fn render_error(&self, err: &Error, input: &str) -> String;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub day: u8,
+    /// Time spent parsing the input into the model.
+    pub parse: Duration,
+    pub answers: Vec<PartAnswer>,
+}
+
+impl Run {
+    pub fn answer(&self, part: Part) -> Option<&PartAnswer> {
+        self.answers.iter().find(|answer| answer.part == part)
+    }
+
+    pub fn total(&self) -> Duration {
+        self.parse
+            + self
+                .answers
+                .iter()
+                .map(|answer| answer.elapsed)
+                .sum::<Duration>()
+    }
+}
+
+pub struct Solver<S>(PhantomData<fn() -> S>);
+
+impl<S> Solver<S> {
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<S> Default for Solver<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Solution> Day for Solver<S> {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn embedded_input(&self) -> Option<&'static str> {
+        S::EMBEDDED_INPUT
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> Option<String> {
+        S::generate(&mut Rng::new(seed), size)
+    }
+
+    fn reference(&self, input: &str, part: Part) -> Option<Answer> {
+        let input = S::NORMALIZE.apply(input);
+        match part {
+            Part::One => S::reference_part1(&input).map(Into::into),
+            Part::Two => S::reference_part2(&input).map(Into::into),
+        }
+    }
+
+    fn trace(&self, input: &str) -> Result<Option<Trace>> {
+        let model = S::parse(&S::NORMALIZE.apply(input))?;
+        Ok(S::trace(&model))
+    }
+
+    fn render_error(&self, err: &Error, input: &str) -> String {
+        err.render(&S::NORMALIZE.apply(input))
+    }
+
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Run> {
+        let start = Instant::now();
+        let model = S::parse(&S::NORMALIZE.apply(input))?;
+        let parse = start.elapsed();
+
+        let answers = parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let (answer, answer_type) = match part {
+                    Part::One => (S::part1(&model)?.into(), any::type_name::<S::Part1>()),
+                    Part::Two => (S::part2(&model)?.into(), any::type_name::<S::Part2>()),
+                };
+
+                Ok(PartAnswer {
+                    day: S::DAY,
+                    part,
+                    answer,
+                    answer_type,
+                    elapsed: start.elapsed(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Run {
+            day: S::DAY,
+            parse,
+            answers,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Sum;
+
+    #[test]
+    fn test_part_from_str() {
+        assert_eq!("1".parse::<Part>(), Ok(Part::One));
+        assert_eq!("2".parse::<Part>(), Ok(Part::Two));
+        assert!("3".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn test_solver() {
+        let day: &dyn Day = &Solver::<Sum>::new();
+        assert_eq!(day.day(), 9);
+
+        let answers = day.solve("1,2,3", &[Part::Two, Part::One]).unwrap();
+        assert_eq!(answers[0].answer, "3");
+        assert_eq!(answers[1].answer, "6");
+        assert_eq!(answers[1].to_string(), "Day 09 part 1: 6");
+        assert_eq!(answers[0].answer_type, "usize");
+        assert_eq!(answers[1].answer_type, "u64");
+
+        assert!(day.solve("1,x", &Part::ALL).is_err());
+
+        let answers = day.solve("\u{feff}1,2,3\r\n", &[Part::One]).unwrap();
+        assert_eq!(answers[0].answer, "6");
+
+        let raw = "\u{feff}1,x\r\n";
+        let err = day.solve(raw, &Part::ALL).unwrap_err();
+        assert!(day.render_error(&err, raw).contains("1 | 1,x\n"));
+
+        let run = day.run("4,5", &[Part::Two]).unwrap();
+        assert_eq!(
+            run.answer(Part::Two).map(|a| &a.answer),
+            Some(&Answer::Number(4))
+        );
+        assert!(run.answer(Part::One).is_none());
+        assert_eq!(run.total(), run.parse + run.answers[0].elapsed);
+    }
+}
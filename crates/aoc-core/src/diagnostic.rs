@@ -0,0 +1,266 @@
+use std::fmt::Write;
+
+use nom::{
+    Parser,
+    error::{ContextError, ErrorKind, FromExternalError, ParseError},
+};
+
+use crate::{Error, Position, Result};
+
+/// nom error type for puzzle parsers. On top of the failing slice and
+/// [`ErrorKind`] it keeps the innermost `context(...)` label, which is used as
+/// the "expected ..." description when reporting the error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NomError<I> {
+    pub input: I,
+    pub kind: ErrorKind,
+    pub expected: Option<String>,
+    /// Why a value that parsed cannot be used, reported as
+    /// [`Error::InvalidValue`] rather than a parse error.
+    pub invalid: Option<String>,
+}
+
+pub type IResult<I, O> = nom::IResult<I, O, NomError<I>>;
+
+impl<I> NomError<I> {
+    /// Error for a value at `input` that has the right shape but cannot be
+    /// used. Wrap it in [`nom::Err::Failure`] so list parsers do not backtrack
+    /// over it and report it as trailing input.
+    pub fn invalid_value(input: I, message: impl Into<String>) -> Self {
+        NomError {
+            input,
+            kind: ErrorKind::Verify,
+            expected: None,
+            invalid: Some(message.into()),
+        }
+    }
+
+    /// Describes what the failing parser was looking for.
+    pub fn description(&self) -> String {
+        if let Some(expected) = &self.expected {
+            return expected.clone();
+        }
+
+        match self.kind {
+            ErrorKind::Digit => "expected a number".to_string(),
+            ErrorKind::Eof => "expected end of input".to_string(),
+            ErrorKind::CrLf => "expected a line ending".to_string(),
+            ErrorKind::Char => "expected a character".to_string(),
+            ErrorKind::Space | ErrorKind::MultiSpace => "expected whitespace".to_string(),
+            kind => format!("unexpected input ({})", kind.description()),
+        }
+    }
+}
+
+impl<I> ParseError<I> for NomError<I> {
+    fn from_error_kind(input: I, kind: ErrorKind) -> Self {
+        NomError {
+            input,
+            kind,
+            expected: None,
+            invalid: None,
+        }
+    }
+
+    fn append(_input: I, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn from_char(input: I, c: char) -> Self {
+        NomError {
+            input,
+            kind: ErrorKind::Char,
+            expected: Some(format!("expected '{}'", c.escape_default())),
+            invalid: None,
+        }
+    }
+}
+
+impl<I> ContextError<I> for NomError<I> {
+    fn add_context(_input: I, ctx: &'static str, mut other: Self) -> Self {
+        if other.expected.is_none() {
+            other.expected = Some(ctx.to_string());
+        }
+        other
+    }
+}
+
+impl<I, E> FromExternalError<I, E> for NomError<I> {
+    fn from_external_error(input: I, kind: ErrorKind, _e: E) -> Self {
+        Self::from_error_kind(input, kind)
+    }
+}
+
+/// Longest stretch of unparsed input quoted in a trailing input error.
+const SNIPPET_LEN: usize = 20;
+
+/// Runs `parser` over `input` and requires it to consume everything but
+/// trailing whitespace. Leftover input is reported at the first character the
+/// parser did not consume.
+pub fn parse_all<'a, P>(input: &'a str, mut parser: P) -> Result<P::Output>
+where
+    P: Parser<&'a str, Error = NomError<&'a str>>,
+{
+    let (remaining, output) = parser.parse(input).map_err(|e| Error::from_nom(input, e))?;
+
+    let rest = remaining.trim_start();
+    if rest.is_empty() {
+        return Ok(output);
+    }
+
+    let line = rest.lines().next().unwrap_or(rest);
+    let snippet = match line.char_indices().nth(SNIPPET_LEN) {
+        Some((end, _)) => format!("{}...", &line[..end]),
+        None => line.to_string(),
+    };
+
+    Err(Error::parse(
+        Position::of_remaining(input, rest),
+        format!("unexpected {:?}, expected end of input", snippet),
+    ))
+}
+
+impl Error {
+    /// Converts a nom error produced while parsing `input` into an owned
+    /// error pointing at the line and column where parsing failed.
+    pub fn from_nom(input: &str, err: nom::Err<NomError<&str>>) -> Self {
+        match err {
+            nom::Err::Incomplete(_) => {
+                Error::parse(Position::locate(input, input.len()), "incomplete input")
+            }
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                let position = Position::of_remaining(input, e.input);
+                match e.invalid {
+                    Some(message) => Error::invalid_value(position, message),
+                    None => Error::parse(position, e.description()),
+                }
+            }
+        }
+    }
+
+    /// Renders the error with the offending line of `input` and a caret under
+    /// the failing column. Errors without a position render as their message.
+    pub fn render(&self, input: &str) -> String {
+        let (label, position) = match self {
+            Error::Parse { position, .. } => ("parse error", *position),
+            Error::InvalidValue { position, .. } => ("invalid value", *position),
+            Error::Solve { .. } | Error::Input { .. } => return self.to_string(),
+        };
+
+        let line = input.lines().nth(position.line - 1).unwrap_or("");
+        let gutter = " ".repeat(position.line.to_string().len());
+        let padding = line
+            .chars()
+            .take(position.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect::<String>();
+
+        let mut out = String::new();
+        let _ = writeln!(out, "{}: {}", label, self.message());
+        let _ = writeln!(out, "{}--> {}", gutter, position);
+        let _ = writeln!(out, "{} |", gutter);
+        let _ = writeln!(out, "{} | {}", position.line, line);
+        let _ = write!(out, "{} | {}^", gutter, padding);
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{Parser, character::complete, error::context};
+
+    use super::*;
+
+    fn direction(input: &str) -> IResult<&str, (char, u64)> {
+        (
+            context("expected 'L' or 'R'", complete::one_of("LR")),
+            complete::u64,
+        )
+            .parse(input)
+    }
+
+    #[test]
+    fn test_context() {
+        let input = "L68\nX30";
+        let err = (direction, complete::newline, direction)
+            .parse(input)
+            .unwrap_err();
+
+        let err = Error::from_nom(input, err);
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 2, column 1: expected 'L' or 'R'"
+        );
+    }
+
+    #[test]
+    fn test_default_descriptions() {
+        let input = "Lxx";
+        let err = direction(input).unwrap_err();
+        assert_eq!(
+            Error::from_nom(input, err).to_string(),
+            "parse error at line 1, column 2: expected a number"
+        );
+
+        let input = "1+2";
+        let err = (complete::u64::<&str, NomError<&str>>, complete::char('-'))
+            .parse(input)
+            .unwrap_err();
+        assert_eq!(Error::from_nom(input, err).message(), "expected '-'");
+    }
+
+    #[test]
+    fn test_invalid_value() {
+        let input = "L68\nR0";
+        let err = nom::Err::Failure(NomError::invalid_value(&input[4..], "zero distance"));
+        assert_eq!(
+            Error::from_nom(input, err).to_string(),
+            "invalid value at line 2, column 1: zero distance"
+        );
+    }
+
+    #[test]
+    fn test_parse_all() {
+        let lines =
+            |input| nom::multi::separated_list1(complete::line_ending, direction).parse(input);
+
+        assert_eq!(parse_all("L68\nR30\n\n", lines).unwrap().len(), 2);
+
+        let err = parse_all("L68\nR30\nX12\nL1", lines).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 3, column 1: unexpected \"X12\", expected end of input"
+        );
+
+        let err = parse_all("L68 this line is rather long", lines).unwrap_err();
+        assert_eq!(
+            err.message(),
+            "unexpected \"this line is rather ...\", expected end of input"
+        );
+
+        let err = parse_all("", lines).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 1, column 1: expected 'L' or 'R'"
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let input = "L68\nR4x\nL1";
+        let err = Error::parse(Position::new(2, 3), "expected a line ending");
+        assert_eq!(
+            err.render(input),
+            "parse error: expected a line ending\n \
+             --> line 2, column 3\n  \
+             |\n\
+             2 | R4x\n  \
+             |   ^"
+        );
+
+        assert_eq!(
+            Error::solve("no start").render(input),
+            "solver failed: no start"
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use std::fmt::{self, Display};
+
+use crate::{Answer, Part, Rng, Solution, Solver, day::Day};
+
+/// First generated input on which a day's solver disagrees with its
+/// reference implementation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Divergence {
+    pub day: u8,
+    pub part: Part,
+    pub seed: u64,
+    pub input: String,
+    /// Answer of the reference implementation.
+    pub expected: Answer,
+    /// Answer of the solver, or the error it failed with.
+    pub actual: Result<Answer, String>,
+}
+
+impl Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let actual = match &self.actual {
+            Ok(answer) => answer.to_string(),
+            Err(e) => format!("error: {}", e),
+        };
+        write!(
+            f,
+            "Day {:02} part {} diverges on seed {}: reference gave {}, solver gave {}\ninput:\n{}",
+            self.day, self.part, self.seed, self.expected, actual, self.input
+        )
+    }
+}
+
+/// Runs `day` and its reference on one part of an input, returning the
+/// reference's answer and the solver's if they disagree.
+pub fn compare_part(
+    day: &dyn Day,
+    input: &str,
+    part: Part,
+) -> Option<(Answer, Result<Answer, String>)> {
+    let expected = day.reference(input, part)?;
+    let actual = day
+        .solve(input, &[part])
+        .map(|answers| answers[0].answer.clone())
+        .map_err(|e| e.to_string());
+
+    (actual.as_ref() != Ok(&expected)).then_some((expected, actual))
+}
+
+/// Runs `day` and its reference on one input, returning the first part where
+/// they disagree with the reference's answer and the solver's.
+pub fn compare(day: &dyn Day, input: &str) -> Option<(Part, Answer, Result<Answer, String>)> {
+    Part::ALL.into_iter().find_map(|part| {
+        compare_part(day, input, part).map(|(expected, actual)| (part, expected, actual))
+    })
+}
+
+/// Generates an input for each seed in `0..cases` and returns the first on
+/// which `day` and its reference disagree.
+pub fn find_divergence(
+    day: &dyn Day,
+    cases: u64,
+    generate: impl Fn(&mut Rng) -> String,
+) -> Option<Divergence> {
+    (0..cases).find_map(|seed| {
+        let input = generate(&mut Rng::new(seed));
+        compare(day, &input).map(|(part, expected, actual)| Divergence {
+            day: day.day(),
+            part,
+            seed,
+            input,
+            expected,
+            actual,
+        })
+    })
+}
+
+/// Panics with the first diverging case, for use in a day's tests.
+/// `generate` should keep inputs small enough for the reference to finish.
+pub fn assert_agree<S: Solution>(cases: u64, generate: impl Fn(&mut Rng) -> String) {
+    if let Some(divergence) = find_divergence(&Solver::<S>::new(), cases, generate) {
+        panic!("{}", divergence);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Sum;
+
+    #[test]
+    fn test_find_divergence() {
+        let day = Solver::<Sum>::new();
+
+        let divergence = find_divergence(&day, 10, |rng| {
+            let last = rng.range(0..=1);
+            format!("1,2,{}", last)
+        })
+        .unwrap();
+        assert_eq!(divergence.part, Part::Two);
+        assert_eq!(divergence.input, "1,2,1");
+        assert_eq!(divergence.expected, "4");
+        assert_eq!(divergence.actual, Ok(Answer::Number(3)));
+        assert_eq!(Rng::new(divergence.seed).range(0..=1), 1);
+
+        assert_eq!(find_divergence(&day, 10, |_| "1,2,0".to_string()), None);
+    }
+}
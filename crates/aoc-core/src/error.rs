@@ -0,0 +1,164 @@
+use std::{
+    fmt::{self, Display},
+    io,
+};
+
+/// 1-based line and column of a character in the puzzle input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Position {
+    pub fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+
+    /// Finds the position of the byte at `offset` in `input`.
+    pub fn locate(input: &str, offset: usize) -> Self {
+        let offset = offset.min(input.len());
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+
+    /// Finds the position where `remaining`, a suffix of `input` left over
+    /// by a parser, begins.
+    pub fn of_remaining(input: &str, remaining: &str) -> Self {
+        Self::locate(input, input.len().saturating_sub(remaining.len()))
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The input does not have the expected shape.
+    Parse { position: Position, message: String },
+    /// The input parsed, but a value in it cannot be used.
+    InvalidValue { position: Position, message: String },
+    /// The solver could not produce an answer from the parsed model.
+    Solve { message: String },
+    /// The input could not be loaded.
+    Input { message: String },
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl Error {
+    pub fn parse(position: Position, message: impl Into<String>) -> Self {
+        Error::Parse {
+            position,
+            message: message.into(),
+        }
+    }
+
+    pub fn invalid_value(position: Position, message: impl Into<String>) -> Self {
+        Error::InvalidValue {
+            position,
+            message: message.into(),
+        }
+    }
+
+    pub fn solve(message: impl Into<String>) -> Self {
+        Error::Solve {
+            message: message.into(),
+        }
+    }
+
+    pub fn position(&self) -> Option<Position> {
+        match self {
+            Error::Parse { position, .. } | Error::InvalidValue { position, .. } => Some(*position),
+            Error::Solve { .. } | Error::Input { .. } => None,
+        }
+    }
+
+    /// Moves the error's position down by `lines`, for errors found in a
+    /// fragment that starts partway through the input.
+    pub fn offset_lines(self, lines: usize) -> Self {
+        match self {
+            Error::Parse { position, message } => Error::Parse {
+                position: Position::new(position.line + lines, position.column),
+                message,
+            },
+            Error::InvalidValue { position, message } => Error::InvalidValue {
+                position: Position::new(position.line + lines, position.column),
+                message,
+            },
+            err @ (Error::Solve { .. } | Error::Input { .. }) => err,
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            Error::Parse { message, .. }
+            | Error::InvalidValue { message, .. }
+            | Error::Solve { message }
+            | Error::Input { message } => message,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse { position, message } => {
+                write!(f, "parse error at {}: {}", position, message)
+            }
+            Error::InvalidValue { position, message } => {
+                write!(f, "invalid value at {}: {}", position, message)
+            }
+            Error::Solve { message } => write!(f, "solver failed: {}", message),
+            Error::Input { message } => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Input {
+            message: err.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locate() {
+        let input = "ab\ncde\n\nf";
+        assert_eq!(Position::locate(input, 0), Position::new(1, 1));
+        assert_eq!(Position::locate(input, 1), Position::new(1, 2));
+        assert_eq!(Position::locate(input, 3), Position::new(2, 1));
+        assert_eq!(Position::locate(input, 5), Position::new(2, 3));
+        assert_eq!(Position::locate(input, 7), Position::new(3, 1));
+        assert_eq!(Position::locate(input, 8), Position::new(4, 1));
+        assert_eq!(Position::locate(input, 100), Position::new(4, 2));
+    }
+
+    #[test]
+    fn test_display() {
+        let err = Error::invalid_value(Position::new(4, 2), "range end is before its start");
+        assert_eq!(
+            err.to_string(),
+            "invalid value at line 4, column 2: range end is before its start"
+        );
+        assert_eq!(
+            Error::solve("no start").to_string(),
+            "solver failed: no start"
+        );
+    }
+}
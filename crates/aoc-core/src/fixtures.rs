@@ -0,0 +1,161 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{Part, Solution, Solver, day::Day};
+
+/// Extension of the sidecar file listing a fixture's expected answers.
+pub const ANSWERS_EXTENSION: &str = "answers";
+
+/// An example input together with the answers the puzzle text gives for it.
+///
+/// Fixtures are stored as `<name>.txt`, read byte for byte so significant
+/// whitespace survives, next to a `<name>.answers` sidecar with one
+/// `<part> <answer>` line per known part. Blank lines and lines starting with
+/// `#` in the sidecar are ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixture {
+    pub name: String,
+    pub input: String,
+    pub answers: Vec<(Part, String)>,
+}
+
+fn invalid_data(path: &Path, message: impl std::fmt::Display) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("{}: {}", path.display(), message),
+    )
+}
+
+/// Parses a sidecar listing expected answers.
+pub fn parse_answers(text: &str) -> Result<Vec<(Part, String)>, String> {
+    text.lines()
+        .enumerate()
+        .map(|(idx, line)| (idx, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(idx, line)| {
+            let part_answer = line
+                .split_once(char::is_whitespace)
+                .ok_or_else(|| "expected `<part> <answer>`".to_string())
+                .and_then(|(part, answer)| Ok((part.parse()?, answer.trim().to_string())));
+            part_answer.map_err(|e: String| format!("line {}: {}", idx + 1, e))
+        })
+        .collect()
+}
+
+impl Fixture {
+    /// Reads `<name>.txt` and its `<name>.answers` sidecar.
+    pub fn load(input_path: &Path) -> io::Result<Self> {
+        let name = input_path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .ok_or_else(|| invalid_data(input_path, "fixture has no name"))?;
+
+        let answers_path = input_path.with_extension(ANSWERS_EXTENSION);
+        let answers =
+            fs::read_to_string(&answers_path).map_err(|e| invalid_data(&answers_path, e))?;
+
+        Ok(Fixture {
+            name,
+            input: fs::read_to_string(input_path)?,
+            answers: parse_answers(&answers).map_err(|e| invalid_data(&answers_path, e))?,
+        })
+    }
+}
+
+/// Loads every `*.txt` fixture in `dir`, sorted by name.
+pub fn load_dir(dir: &Path) -> io::Result<Vec<Fixture>> {
+    let mut paths = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<PathBuf>>>()?;
+    paths.retain(|path| path.extension().is_some_and(|ext| ext == "txt"));
+    paths.sort();
+
+    paths.iter().map(|path| Fixture::load(path)).collect()
+}
+
+/// Solves every fixture in `dir` and panics listing each answer that differs
+/// from its sidecar. A day without fixtures fails too, so a missing directory
+/// is noticed rather than silently passing.
+pub fn check_examples<S: Solution>(dir: &Path) {
+    let fixtures = load_dir(dir)
+        .unwrap_or_else(|e| panic!("cannot load fixtures from {}: {}", dir.display(), e));
+    assert!(!fixtures.is_empty(), "no fixtures in {}", dir.display());
+
+    let mut failures = Vec::new();
+    for fixture in &fixtures {
+        let parts = fixture
+            .answers
+            .iter()
+            .map(|(part, _)| *part)
+            .collect::<Vec<_>>();
+        let solver = Solver::<S>::new();
+        let given = match solver.solve(&fixture.input, &parts) {
+            Ok(given) => given,
+            Err(e) => {
+                let rendered = solver.render_error(&e, &fixture.input);
+                failures.push(format!("{}: {}", fixture.name, rendered));
+                continue;
+            }
+        };
+
+        for ((part, expected), answer) in fixture.answers.iter().zip(given) {
+            if answer.answer != expected.as_str() {
+                failures.push(format!(
+                    "{}: part {} gave {}, expected {}",
+                    fixture.name, part, answer.answer, expected
+                ));
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// Generates the standard tests for a day: one solving every example fixture
+/// in the crate's `fixtures` directory, and one checking the real input
+/// against the known answers. The second needs files that are not checked
+/// in, so it is ignored unless asked for.
+#[macro_export]
+macro_rules! solution_tests {
+    ($solution:ty) => {
+        #[test]
+        fn test_examples() {
+            $crate::fixtures::check_examples::<$solution>(::std::path::Path::new(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/fixtures"
+            )));
+        }
+
+        #[test]
+        #[ignore = "needs the real input and inputs/answers.txt"]
+        fn test_known_answers() {
+            $crate::answers::assert_known::<$solution>();
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        assert_eq!(
+            parse_answers("# from the puzzle text\n1 357\n\n2 3121910778619\n"),
+            Ok(vec![
+                (Part::One, "357".to_string()),
+                (Part::Two, "3121910778619".to_string()),
+            ])
+        );
+        assert_eq!(
+            parse_answers("1 357\n357"),
+            Err("line 2: expected `<part> <answer>`".to_string())
+        );
+        assert_eq!(
+            parse_answers("3 357"),
+            Err("line 1: invalid part `3`, expected 1 or 2".to_string())
+        );
+    }
+}
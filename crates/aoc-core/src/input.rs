@@ -0,0 +1,169 @@
+use std::{
+    env,
+    fmt::{self, Display},
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Directory, relative to the workspace root, holding `dayNN.txt` inputs.
+pub const INPUTS_DIR: &str = "inputs";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Path(PathBuf),
+    Stdin,
+    Embedded(&'static str),
+}
+
+impl InputSource {
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            InputSource::Path(path) => fs::read_to_string(path).map_err(|e| {
+                io::Error::new(
+                    e.kind(),
+                    format!("failed to read {}: {}", path.display(), e),
+                )
+            }),
+            InputSource::Stdin => {
+                let mut buf = String::new();
+                io::stdin().read_to_string(&mut buf)?;
+                Ok(buf)
+            }
+            InputSource::Embedded(input) => Ok(input.to_string()),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Path(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::Embedded(_) => write!(f, "embedded"),
+        }
+    }
+}
+
+/// Environment variable naming the input file for one day, such as
+/// `AOC_INPUT_DAY07`, used when no path argument is given. It is per day so
+/// that commands running several days never feed them all the same file.
+pub fn env_var(day: u8) -> String {
+    format!("AOC_INPUT_DAY{:02}", day)
+}
+
+pub fn file_name(day: u8) -> String {
+    format!("day{:02}.txt", day)
+}
+
+/// Looks for `inputs/<name>` in the current directory and its ancestors,
+/// falling back to the workspace this crate was built in.
+pub fn locate(name: &str) -> Option<PathBuf> {
+    let relative = Path::new(INPUTS_DIR).join(name);
+
+    let cwd = env::current_dir().ok();
+    let workspace_root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+
+    cwd.iter()
+        .flat_map(|dir| dir.ancestors())
+        .chain(std::iter::once(workspace_root.as_path()))
+        .map(|dir| dir.join(&relative))
+        .find(|path| path.is_file())
+}
+
+pub fn default_path(day: u8) -> Option<PathBuf> {
+    locate(&file_name(day))
+}
+
+/// Short stable fingerprint of an input, used to tell inputs apart without
+/// storing them. 64-bit FNV-1a, rendered as 16 hex digits.
+pub fn hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/// Picks the input for `day`, in order of preference: an explicit path
+/// argument (`-` meaning stdin), the day's [`env_var`], an
+/// input embedded at compile time, then `inputs/dayNN.txt`.
+pub fn resolve(
+    day: u8,
+    arg: Option<&str>,
+    embedded: Option<&'static str>,
+) -> io::Result<InputSource> {
+    if let Some(arg) = arg {
+        return Ok(match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::Path(PathBuf::from(path)),
+        });
+    }
+
+    if let Some(path) = env::var_os(env_var(day)) {
+        return Ok(InputSource::Path(PathBuf::from(path)));
+    }
+
+    if let Some(input) = embedded {
+        return Ok(InputSource::Embedded(input));
+    }
+
+    default_path(day).map(InputSource::Path).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!(
+                "no input for day {:02}: pass a path (or `-` for stdin), set {} or create {}/{}",
+                day,
+                env_var(day),
+                INPUTS_DIR,
+                file_name(day)
+            ),
+        )
+    })
+}
+
+/// Resolves and reads the input for a day binary, taking the path from the
+/// first command line argument.
+pub fn load(day: u8, embedded: Option<&'static str>) -> io::Result<String> {
+    let arg = env::args().nth(1);
+    resolve(day, arg.as_deref(), embedded)?.read()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_name() {
+        assert_eq!(file_name(1), "day01.txt");
+        assert_eq!(file_name(12), "day12.txt");
+    }
+
+    #[test]
+    fn test_env_var() {
+        assert_eq!(env_var(7), "AOC_INPUT_DAY07");
+        assert_eq!(env_var(12), "AOC_INPUT_DAY12");
+    }
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(hash(""), "cbf29ce484222325");
+        assert_eq!(hash("a"), "af63dc4c8601ec8c");
+        assert_ne!(hash("L68\nL30"), hash("L68\nL31"));
+    }
+
+    #[test]
+    fn test_resolve_argument() {
+        assert_eq!(
+            resolve(1, Some("some/file.txt"), Some("embedded")).unwrap(),
+            InputSource::Path(PathBuf::from("some/file.txt"))
+        );
+        assert_eq!(resolve(1, Some("-"), None).unwrap(), InputSource::Stdin);
+    }
+
+    #[test]
+    fn test_read_embedded() {
+        let source = InputSource::Embedded("L68\nR48\n");
+        assert_eq!(source.read().unwrap(), "L68\nR48\n");
+        assert_eq!(source.to_string(), "embedded");
+    }
+}
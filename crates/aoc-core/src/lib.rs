@@ -0,0 +1,93 @@
+use std::fmt::Display;
+
+pub mod answer;
+pub mod answers;
+pub mod attempts;
+pub mod day;
+pub mod diagnostic;
+pub mod differential;
+pub mod error;
+pub mod fixtures;
+pub mod input;
+pub mod normalize;
+pub mod random;
+pub mod shrink;
+pub mod stream;
+#[cfg(test)]
+mod testing;
+pub mod trace;
+
+pub use answer::Answer;
+pub use day::{Day, Part, PartAnswer, Run, Solver};
+pub use error::{Error, Position, Result};
+pub use normalize::{Normalize, TrailingNewline};
+pub use random::Rng;
+pub use trace::Trace;
+
+/// A single day's puzzle: parse the input once into a typed model, then
+/// answer both parts from that model.
+pub trait Solution {
+    /// Puzzle day, 1-based.
+    const DAY: u8;
+
+    /// Input compiled into the binary, used when none is given at runtime.
+    const EMBEDDED_INPUT: Option<&'static str> = None;
+
+    /// Clean-up applied to the raw input before [`Solution::parse`] sees it.
+    const NORMALIZE: Normalize = Normalize::DEFAULT;
+
+    type Model;
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Model>;
+
+    fn part1(model: &Self::Model) -> Result<Self::Part1>;
+
+    fn part2(model: &Self::Model) -> Result<Self::Part2>;
+
+    /// Produces a random valid input of roughly `size` records, for stress
+    /// testing. Days without a generator return `None`.
+    fn generate(_rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
+
+    /// Slow but obviously correct part 1 answer computed straight from the
+    /// normalized input, used to cross-check [`Solution::part1`]. Only
+    /// practical on small inputs; `None` when the day has no reference or it
+    /// cannot answer this input.
+    fn reference_part1(_input: &str) -> Option<Self::Part1> {
+        None
+    }
+
+    /// Part 2 counterpart of [`Solution::reference_part1`].
+    fn reference_part2(_input: &str) -> Option<Self::Part2> {
+        None
+    }
+
+    /// Step by step record of how the answers follow from `model`, for
+    /// debugging a wrong answer. Days without a trace return `None`.
+    fn trace(_model: &Self::Model) -> Option<Trace> {
+        None
+    }
+}
+
+fn exit_with(err: impl Display) -> ! {
+    eprintln!("error: {}", err);
+    std::process::exit(1);
+}
+
+/// Entry point shared by the per-day binaries: loads the input, then prints
+/// both answers.
+pub fn main<S: Solution>() {
+    let input = input::load(S::DAY, S::EMBEDDED_INPUT).unwrap_or_else(|e| exit_with(e));
+
+    let solver = Solver::<S>::new();
+    let answers = solver
+        .solve(&input, &Part::ALL)
+        .unwrap_or_else(|e| exit_with(solver.render_error(&e, &input)));
+
+    for answer in answers {
+        println!("{}", answer);
+    }
+}
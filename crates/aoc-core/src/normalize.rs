@@ -0,0 +1,135 @@
+use std::borrow::Cow;
+
+/// What to do with newlines at the very end of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrailingNewline {
+    Keep,
+    /// Remove every trailing newline, so the last line is unterminated.
+    Strip,
+    /// End the input with exactly one newline.
+    Single,
+}
+
+/// How a day wants its raw input cleaned up before parsing. Applied to every
+/// input the day is run on, so a file edited on Windows or pasted from a
+/// browser parses the same as the original download.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Normalize {
+    /// Drop a leading UTF-8 byte order mark.
+    pub strip_bom: bool,
+    /// Turn `\r\n` line endings into `\n`.
+    pub unix_line_endings: bool,
+    /// Remove spaces and tabs at the end of every line. Days where columns
+    /// are aligned with trailing spaces must turn this off.
+    pub trim_line_ends: bool,
+    pub trailing_newline: TrailingNewline,
+}
+
+impl Normalize {
+    /// Clean up everything; suits most line-oriented puzzles.
+    pub const DEFAULT: Normalize = Normalize {
+        strip_bom: true,
+        unix_line_endings: true,
+        trim_line_ends: true,
+        trailing_newline: TrailingNewline::Strip,
+    };
+
+    /// Pass the input through untouched.
+    pub const RAW: Normalize = Normalize {
+        strip_bom: false,
+        unix_line_endings: false,
+        trim_line_ends: false,
+        trailing_newline: TrailingNewline::Keep,
+    };
+
+    /// Applies the policy, borrowing `input` when nothing needs to change.
+    pub fn apply<'a>(&self, input: &'a str) -> Cow<'a, str> {
+        let mut text = Cow::Borrowed(input);
+
+        if self.strip_bom
+            && let Some(rest) = input.strip_prefix('\u{feff}')
+        {
+            text = Cow::Borrowed(rest);
+        }
+
+        if self.unix_line_endings && text.contains("\r\n") {
+            text = Cow::Owned(text.replace("\r\n", "\n"));
+        }
+
+        let padded = |line: &str| line.ends_with([' ', '\t']);
+        if self.trim_line_ends && text.split('\n').any(padded) {
+            text = Cow::Owned(
+                text.split('\n')
+                    .map(|line| line.trim_end_matches([' ', '\t']))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            );
+        }
+
+        match self.trailing_newline {
+            TrailingNewline::Keep => text,
+            TrailingNewline::Strip => match text {
+                Cow::Borrowed(text) => Cow::Borrowed(text.trim_end_matches('\n')),
+                Cow::Owned(text) => Cow::Owned(text.trim_end_matches('\n').to_string()),
+            },
+            TrailingNewline::Single => {
+                let trimmed = text.trim_end_matches('\n');
+                if trimmed.is_empty() || text.len() == trimmed.len() + 1 {
+                    text
+                } else {
+                    Cow::Owned(format!("{}\n", trimmed))
+                }
+            }
+        }
+    }
+}
+
+impl Default for Normalize {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        let normalize = Normalize::DEFAULT;
+        assert_eq!(normalize.apply("L68\nR30\n"), "L68\nR30");
+        assert_eq!(normalize.apply("\u{feff}L68\r\nR30 \r\n\r\n"), "L68\nR30");
+        assert_eq!(normalize.apply("3-5\t\n\n1 \n5"), "3-5\n\n1\n5");
+        assert!(matches!(normalize.apply("L68\nR30\n"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_keep_trailing_spaces() {
+        let normalize = Normalize {
+            trim_line_ends: false,
+            ..Normalize::DEFAULT
+        };
+        assert_eq!(
+            normalize.apply("123 328 \r\n 45 64  \r\n*   +  \r\n"),
+            "123 328 \n 45 64  \n*   +  "
+        );
+    }
+
+    #[test]
+    fn test_single_trailing_newline() {
+        let normalize = Normalize {
+            trailing_newline: TrailingNewline::Single,
+            ..Normalize::RAW
+        };
+        assert_eq!(normalize.apply("a\nb"), "a\nb\n");
+        assert_eq!(normalize.apply("a\nb\n\n\n"), "a\nb\n");
+        assert!(matches!(normalize.apply("a\nb\n"), Cow::Borrowed(_)));
+        assert_eq!(normalize.apply(""), "");
+    }
+
+    #[test]
+    fn test_raw() {
+        let input = "\u{feff}a \r\nb\n\n";
+        assert_eq!(Normalize::RAW.apply(input), input);
+    }
+}
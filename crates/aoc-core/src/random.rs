@@ -0,0 +1,95 @@
+use std::ops::RangeInclusive;
+
+/// Small seeded random number generator (SplitMix64) for generating puzzle
+/// inputs. Implemented here rather than pulled in so a seed produces the same
+/// input on every machine and every version of the workspace.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `0..n`. `n` must not be zero.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "cannot pick below zero");
+        // Reject the top sliver of values that would bias the modulo
+        let zone = u64::MAX - (u64::MAX - n + 1) % n;
+        loop {
+            let value = self.next_u64();
+            if value <= zone {
+                return value % n;
+            }
+        }
+    }
+
+    /// Uniform value in `range`, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "cannot pick from an empty range");
+        match (end - start).checked_add(1) {
+            Some(len) => start + self.below(len),
+            None => self.next_u64(),
+        }
+    }
+
+    /// Uniform index into a collection of `len` items.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// True with probability `numerator / denominator`.
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeded() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        let a = (0..5).map(|_| a.next_u64()).collect::<Vec<_>>();
+        let b = (0..5).map(|_| b.next_u64()).collect::<Vec<_>>();
+        assert_eq!(a, b);
+        assert_ne!(a[0], Rng::new(43).next_u64());
+
+        // Reference output of SplitMix64 seeded with 0
+        assert_eq!(Rng::new(0).next_u64(), 0xe220_a839_7b1d_cdaf);
+    }
+
+    #[test]
+    fn test_range() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            assert!((10..=12).contains(&rng.range(10..=12)));
+            assert!(rng.below(3) < 3);
+        }
+        assert_eq!(rng.range(5..=5), 5);
+        rng.range(0..=u64::MAX);
+
+        let seen = (0..1000).fold([false; 3], |mut seen, _| {
+            seen[rng.index(3)] = true;
+            seen
+        });
+        assert_eq!(seen, [true; 3]);
+    }
+}
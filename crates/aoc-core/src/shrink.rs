@@ -0,0 +1,267 @@
+use std::{
+    fmt::{self, Display},
+    panic::{self, AssertUnwindSafe},
+};
+
+use crate::{Part, day::Day, differential};
+
+/// Behaviour a reduced input must keep showing to count as a reproducer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Predicate {
+    /// The solver panics on one of the parts.
+    Panic,
+    /// The solver disagrees with the day's reference on one of the parts.
+    Mismatch,
+    /// The solver gives this answer for one of the parts.
+    Answer(String),
+}
+
+impl Predicate {
+    /// Whether `input` still shows the behaviour. Inputs the day cannot parse
+    /// never do, so shrinking only ever keeps valid inputs. A panic only counts
+    /// for [`Predicate::Panic`]; anywhere else, including in the reference, it
+    /// means the input is not a reproducer.
+    pub fn holds(&self, day: &dyn Day, input: &str, parts: &[Part]) -> bool {
+        let outcome = panic::catch_unwind(AssertUnwindSafe(|| match self {
+            Predicate::Panic => {
+                day.run(input, parts).ok();
+                false
+            }
+            Predicate::Mismatch => {
+                day.run(input, &[]).is_ok()
+                    && parts
+                        .iter()
+                        .any(|&part| differential::compare_part(day, input, part).is_some())
+            }
+            Predicate::Answer(answer) => parts.iter().any(|&part| {
+                day.solve(input, &[part])
+                    .is_ok_and(|given| given[0].answer == answer.as_str())
+            }),
+        }));
+
+        match outcome {
+            Ok(holds) => holds,
+            Err(_) => *self == Predicate::Panic,
+        }
+    }
+}
+
+impl Display for Predicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Predicate::Panic => write!(f, "a panic"),
+            Predicate::Mismatch => write!(f, "a mismatch with the reference"),
+            Predicate::Answer(answer) => write!(f, "the answer {}", answer),
+        }
+    }
+}
+
+/// Smallest input found, and how many candidates were tried to find it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shrunk {
+    pub input: String,
+    pub attempts: usize,
+}
+
+struct Shrinker<F> {
+    holds: F,
+    trailing_newline: bool,
+    attempts: usize,
+}
+
+impl<F: FnMut(&str) -> bool> Shrinker<F> {
+    fn text(&self, lines: &[String]) -> String {
+        let mut text = lines.join("\n");
+        if self.trailing_newline && !lines.is_empty() {
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Removes as many of `items` as it can while the lines built from the
+    /// rest still satisfy the predicate. Tries removing halves first, then
+    /// ever smaller chunks down to single items.
+    fn ddmin<T: Clone>(
+        &mut self,
+        mut items: Vec<T>,
+        to_lines: impl Fn(&[T]) -> Vec<String>,
+    ) -> Vec<T> {
+        let mut granularity = 2;
+
+        while !items.is_empty() {
+            let chunk = items.len().div_ceil(granularity.min(items.len()));
+            let mut reduced = false;
+
+            let mut start = 0;
+            while start < items.len() {
+                let end = (start + chunk).min(items.len());
+                let candidate = [&items[..start], &items[end..]].concat();
+
+                let text = self.text(&to_lines(&candidate));
+                self.attempts += 1;
+                if (self.holds)(&text) {
+                    items = candidate;
+                    reduced = true;
+                } else {
+                    start = end;
+                }
+            }
+
+            if reduced {
+                granularity = (granularity - 1).max(2);
+            } else if chunk == 1 {
+                break;
+            } else {
+                granularity *= 2;
+            }
+        }
+
+        items
+    }
+
+    fn remove_lines(&mut self, lines: Vec<String>) -> Vec<String> {
+        self.ddmin(lines, <[String]>::to_vec)
+    }
+
+    /// Removes comma-separated items, such as ranges, from each line.
+    fn remove_items(&mut self, mut lines: Vec<String>) -> Vec<String> {
+        for idx in 0..lines.len() {
+            let items = lines[idx].split(',').map(String::from).collect::<Vec<_>>();
+            if items.len() < 2 {
+                continue;
+            }
+
+            let kept = self.ddmin(items, |items| {
+                let mut candidate = lines.clone();
+                candidate[idx] = items.join(",");
+                candidate
+            });
+            lines[idx] = kept.join(",");
+        }
+        lines
+    }
+
+    /// Removes character columns from every line at once, so grids stay
+    /// aligned.
+    fn remove_columns(&mut self, lines: Vec<String>) -> Vec<String> {
+        let width = lines.iter().map(|line| line.chars().count()).max();
+        let columns = (0..width.unwrap_or(0)).collect::<Vec<_>>();
+
+        let keep = |columns: &[usize]| {
+            let mut kept = vec![false; width.unwrap_or(0)];
+            columns.iter().for_each(|&column| kept[column] = true);
+            lines
+                .iter()
+                .map(|line| {
+                    line.chars()
+                        .enumerate()
+                        .filter(|&(column, _)| kept[column])
+                        .map(|(_, c)| c)
+                        .collect()
+                })
+                .collect::<Vec<String>>()
+        };
+
+        let columns = self.ddmin(columns, keep);
+        keep(&columns)
+    }
+}
+
+/// Delta-debugs `input` down to a smaller one for which `holds` is still
+/// true. Removes whole lines, then comma-separated items within lines, then
+/// character columns, and repeats until a full round removes nothing.
+/// `holds` should be true for `input` itself.
+pub fn shrink(input: &str, holds: impl FnMut(&str) -> bool) -> Shrunk {
+    let mut shrinker = Shrinker {
+        holds,
+        trailing_newline: input.ends_with('\n'),
+        attempts: 0,
+    };
+    let mut lines = input.lines().map(String::from).collect::<Vec<_>>();
+
+    loop {
+        let before = shrinker.text(&lines);
+
+        lines = shrinker.remove_lines(lines);
+        lines = shrinker.remove_items(lines);
+        lines = shrinker.remove_columns(lines);
+
+        if shrinker.text(&lines) == before {
+            return Shrunk {
+                input: before,
+                attempts: shrinker.attempts,
+            };
+        }
+    }
+}
+
+/// Shrinks an input on which `day` shows the behaviour in `predicate` for
+/// `parts`, or returns `None` if the input does not show it to begin with.
+pub fn minimize(
+    day: &dyn Day,
+    input: &str,
+    predicate: &Predicate,
+    parts: &[Part],
+) -> Option<Shrunk> {
+    predicate
+        .holds(day, input, parts)
+        .then(|| shrink(input, |candidate| predicate.holds(day, candidate, parts)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Solver, testing::Sum};
+
+    #[test]
+    fn test_shrink_lines() {
+        let input = (1..=20).map(|n| format!("{}\n", n)).collect::<String>();
+        let shrunk = shrink(&input, |candidate| {
+            let lines = candidate.lines().collect::<Vec<_>>();
+            lines.contains(&"7") && lines.contains(&"13")
+        });
+        assert_eq!(shrunk.input, "7\n13\n");
+        assert!(shrunk.attempts > 0);
+    }
+
+    #[test]
+    fn test_shrink_items() {
+        let shrunk = shrink("11-22,95-115,998-1012", |candidate| {
+            candidate.split(',').any(|item| item == "95-115")
+        });
+        assert_eq!(shrunk.input, "95-115");
+    }
+
+    #[test]
+    fn test_shrink_columns() {
+        let input =
+            ".......S.......\n...............\n.......^.......\n...............\n.......^.......";
+        let shrunk = shrink(input, |candidate| {
+            candidate.lines().filter(|line| line.contains('^')).count() >= 2
+        });
+        assert_eq!(shrunk.input, "^\n^");
+    }
+
+    #[test]
+    fn test_minimize() {
+        let day = Solver::<Sum>::new();
+
+        let shrunk = minimize(&day, "1,2,13,4,5", &Predicate::Panic, &[Part::One]).unwrap();
+        assert_eq!(shrunk.input, "13");
+
+        let shrunk = minimize(&day, "1,2,3", &Predicate::Mismatch, &Part::ALL).unwrap();
+        assert_eq!(shrunk.input, "3");
+
+        let answer = Predicate::Answer("7".to_string());
+        let shrunk = minimize(&day, "0,7,0", &answer, &[Part::One]).unwrap();
+        assert_eq!(shrunk.input, "7");
+        let shrunk = minimize(&day, "7,1", &answer, &Part::ALL).unwrap();
+        assert_eq!(shrunk.input, "7");
+
+        assert_eq!(minimize(&day, "1,2,3", &Predicate::Panic, &Part::ALL), None);
+        assert_eq!(
+            minimize(&day, "1,2,x", &Predicate::Mismatch, &Part::ALL),
+            None
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use std::io::BufRead;
+
+use nom::Parser;
+
+use crate::{
+    Result,
+    diagnostic::{NomError, parse_all},
+};
+
+/// Calls `f` with the 1-based number and text of every line read from
+/// `reader`, reusing one buffer so memory stays constant however long the
+/// input is. Lines are cleaned up like [`crate::Normalize::DEFAULT`] would:
+/// no byte order mark, no `\r\n` or `\n` terminator and no trailing spaces
+/// or tabs.
+pub fn for_each_line<R, F>(mut reader: R, mut f: F) -> Result<()>
+where
+    R: BufRead,
+    F: FnMut(usize, &str) -> Result<()>,
+{
+    let mut buf = String::new();
+    let mut line_number = 0;
+
+    loop {
+        buf.clear();
+        if reader.read_line(&mut buf)? == 0 {
+            return Ok(());
+        }
+        line_number += 1;
+
+        let mut line = buf.trim_end_matches(['\n', '\r', ' ', '\t']);
+        if line_number == 1 {
+            line = line.strip_prefix('\u{feff}').unwrap_or(line);
+        }
+        f(line_number, line)?;
+    }
+}
+
+/// Parses a single line read by [`for_each_line`], requiring `parser` to
+/// consume all of it. Errors point at `line_number` in the whole input.
+pub fn parse_line<'a, P>(line_number: usize, line: &'a str, parser: P) -> Result<P::Output>
+where
+    P: Parser<&'a str, Error = NomError<&'a str>>,
+{
+    parse_all(line, parser).map_err(|e| e.offset_lines(line_number - 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::character::complete;
+
+    use super::*;
+
+    #[test]
+    fn test_for_each_line() {
+        let input = "\u{feff}L68 \r\nR30\n\nL5";
+        let mut lines = Vec::new();
+        for_each_line(input.as_bytes(), |n, line| {
+            lines.push((n, line.to_string()));
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(
+            lines,
+            vec![
+                (1, "L68".to_string()),
+                (2, "R30".to_string()),
+                (3, String::new()),
+                (4, "L5".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(parse_line(3, "42", complete::u64), Ok(42));
+
+        let err = parse_line(3, "4x", complete::u64).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 3, column 2: unexpected \"x\", expected end of input"
+        );
+    }
+}
@@ -0,0 +1,42 @@
+//! A small solution for the crate's own tests.
+
+use crate::{Error, Position, Result, Solution};
+
+/// Sums comma separated numbers, with two planted bugs: part 1 panics on 13
+/// and part 2 forgets the last number, which its reference does not.
+pub struct Sum;
+
+impl Solution for Sum {
+    const DAY: u8 = 9;
+
+    type Model = Vec<u64>;
+    type Part1 = u64;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        input
+            .split(',')
+            .map(|n| {
+                n.parse()
+                    .map_err(|_| Error::parse(Position::new(1, 1), "not a number"))
+            })
+            .collect()
+    }
+
+    fn part1(model: &Self::Model) -> Result<u64> {
+        assert!(!model.contains(&13), "unlucky");
+        Ok(model.iter().sum())
+    }
+
+    fn part2(model: &Self::Model) -> Result<usize> {
+        Ok(model[..model.len() - 1].iter().sum::<u64>() as usize)
+    }
+
+    fn reference_part1(input: &str) -> Option<u64> {
+        Some(input.split(',').map(|n| n.parse::<u64>().unwrap()).sum())
+    }
+
+    fn reference_part2(input: &str) -> Option<usize> {
+        Self::reference_part1(input).map(|sum| sum as usize)
+    }
+}
@@ -0,0 +1,166 @@
+use std::fmt::{self, Display};
+
+use crate::Answer;
+
+/// Step by step record of how a solver reached its answers, one row per step,
+/// for finding out why an answer is wrong. Cells are [`Answer`]s, so they
+/// take the same conversions and serialize the same way. Displays as an
+/// aligned table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace {
+    columns: Vec<&'static str>,
+    rows: Vec<Vec<Answer>>,
+}
+
+impl Trace {
+    pub fn new(columns: &[&'static str]) -> Self {
+        Trace {
+            columns: columns.to_vec(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Appends a row, which must have one value per column.
+    pub fn push(&mut self, row: Vec<Answer>) {
+        assert_eq!(
+            row.len(),
+            self.columns.len(),
+            "a trace row needs one value per column"
+        );
+        self.rows.push(row);
+    }
+
+    pub fn columns(&self) -> &[&'static str] {
+        &self.columns
+    }
+
+    pub fn rows(&self) -> &[Vec<Answer>] {
+        &self.rows
+    }
+
+    /// The trace as CSV with a header row. Fields holding commas, quotes or
+    /// line breaks are quoted.
+    pub fn to_csv(&self) -> String {
+        let mut csv = csv_line(self.columns.iter().map(|column| column.to_string()));
+        for row in &self.rows {
+            csv.push_str(&csv_line(row.iter().map(Answer::to_string)));
+        }
+        csv
+    }
+}
+
+fn csv_line(fields: impl Iterator<Item = String>) -> String {
+    let fields = fields
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field
+            }
+        })
+        .collect::<Vec<_>>();
+    format!("{}\n", fields.join(","))
+}
+
+/// Columns are padded to their widest cell, with numbers aligned right.
+impl Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cells = self
+            .rows
+            .iter()
+            .map(|row| row.iter().map(Answer::to_string).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let widths = (0..self.columns.len())
+            .map(|idx| {
+                cells
+                    .iter()
+                    .map(|row| row[idx].chars().count())
+                    .chain([self.columns[idx].chars().count()])
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect::<Vec<_>>();
+        let numeric = (0..self.columns.len())
+            .map(|idx| {
+                !self.rows.is_empty()
+                    && self
+                        .rows
+                        .iter()
+                        .all(|row| matches!(row[idx], Answer::Number(_)))
+            })
+            .collect::<Vec<_>>();
+
+        let mut write_line = |fields: &[String]| {
+            let line = fields
+                .iter()
+                .enumerate()
+                .map(|(idx, field)| {
+                    if numeric[idx] {
+                        format!("{:>1$}", field, widths[idx])
+                    } else {
+                        format!("{:<1$}", field, widths[idx])
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("  ");
+            writeln!(f, "{}", line.trim_end())
+        };
+
+        let header = self
+            .columns
+            .iter()
+            .map(|column| column.to_string())
+            .collect::<Vec<_>>();
+        write_line(&header)?;
+        write_line(&widths.iter().map(|&w| "-".repeat(w)).collect::<Vec<_>>())?;
+        for row in &cells {
+            write_line(row)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trace() -> Trace {
+        let mut trace = Trace::new(&["step", "rotation", "zero"]);
+        trace.push(vec![1.into(), "L68".into(), "false".into()]);
+        trace.push(vec![10.into(), "R1000".into(), "true".into()]);
+        trace
+    }
+
+    #[test]
+    fn test_table() {
+        assert_eq!(
+            trace().to_string(),
+            "\
+step  rotation  zero
+----  --------  -----
+   1  L68       false
+  10  R1000     true
+"
+        );
+        assert_eq!(Trace::new(&["a", "bc"]).to_string(), "a  bc\n-  --\n");
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            trace().to_csv(),
+            "step,rotation,zero\n1,L68,false\n10,R1000,true\n"
+        );
+
+        let mut trace = Trace::new(&["note"]);
+        trace.push(vec!["say \"hi\", twice".into()]);
+        assert_eq!(trace.to_csv(), "note\n\"say \"\"hi\"\", twice\"\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "one value per column")]
+    fn test_row_width() {
+        trace().push(vec![1.into()]);
+    }
+}
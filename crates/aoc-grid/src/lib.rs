@@ -0,0 +1,428 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use aoc_core::{Error, Position, Result};
+
+/// 0-based row and column of a cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub row: usize,
+    pub column: usize,
+}
+
+impl Point {
+    pub fn new(row: usize, column: usize) -> Self {
+        Self { row, column }
+    }
+
+    /// The 1-based position of this cell in the input it was parsed from.
+    pub fn position(self) -> Position {
+        Position::new(self.row + 1, self.column + 1)
+    }
+}
+
+/// Offsets of the neighbours sharing an edge: up, left, right, down.
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Offsets of all eight surrounding neighbours, row by row.
+const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular map of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from rows, or returns `None` if they differ in length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses a character map, turning each cell into a `T` with `cell`,
+    /// which returns a message for characters it does not accept. Every line
+    /// must be as wide as the first.
+    pub fn parse<F>(input: &str, mut cell: F) -> Result<Self>
+    where
+        F: FnMut(Point, char) -> Result<T, String>,
+    {
+        let width = input.lines().next().map_or(0, |line| line.chars().count());
+        let rows = input
+            .lines()
+            .enumerate()
+            .map(|(row, line)| {
+                let cells = line
+                    .chars()
+                    .enumerate()
+                    .map(|(column, ch)| {
+                        let point = Point::new(row, column);
+                        cell(point, ch).map_err(|e| Error::invalid_value(point.position(), e))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                if cells.len() != width {
+                    return Err(Error::parse(
+                        Point::new(row, cells.len().min(width)).position(),
+                        format!("row is {} wide, expected {}", cells.len(), width),
+                    ));
+                }
+                Ok(cells)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self::from_rows(rows).expect("rows should all be as wide as the first"))
+    }
+
+    /// Like [`Grid::parse`], but lines shorter than the longest one are
+    /// padded with `fill` first, for maps whose trailing blanks may have
+    /// been trimmed.
+    pub fn parse_padded<F>(input: &str, fill: char, cell: F) -> Result<Self>
+    where
+        F: FnMut(Point, char) -> Result<T, String>,
+    {
+        let width = input.lines().map(|line| line.chars().count()).max();
+        let padded = input
+            .lines()
+            .map(|line| {
+                let padding = width.unwrap_or(0) - line.chars().count();
+                line.chars()
+                    .chain(std::iter::repeat_n(fill, padding))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Self::parse(&padded, cell)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.row < self.height && point.column < self.width
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.row * self.width + point.column])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.cells[point.row * self.width + point.column])
+    }
+
+    /// The point `rows` down and `columns` right of `point`, if it is inside
+    /// the grid.
+    pub fn offset(&self, point: Point, rows: isize, columns: isize) -> Option<Point> {
+        let moved = Point::new(
+            point.row.checked_add_signed(rows)?,
+            point.column.checked_add_signed(columns)?,
+        );
+        self.contains(moved).then_some(moved)
+    }
+
+    /// The up to four cells sharing an edge with `point`.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |&(rows, columns)| self.offset(point, rows, columns))
+    }
+
+    /// The up to eight cells surrounding `point`, diagonals included.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        SURROUNDING
+            .iter()
+            .filter_map(move |&(rows, columns)| self.offset(point, rows, columns))
+    }
+
+    /// Every cell with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(idx, cell)| (Point::new(idx / self.width, idx % self.width), cell))
+    }
+
+    /// Points of the cells matching `predicate`, row by row.
+    pub fn positions<P>(&self, mut predicate: P) -> impl Iterator<Item = Point>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    /// Point of the first cell matching `predicate`, such as a start marker.
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.positions(predicate).next()
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        (row < self.height).then(|| &self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on zero, and an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of one column, top to bottom. Empty if out of bounds.
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        let height = if column < self.width { self.height } else { 0 };
+        (0..height).map(move |row| &self.cells[row * self.width + column])
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Builds a `width` by `height` grid whose cell at each point is taken
+    /// from this grid at `source(point)`.
+    fn rearranged(&self, width: usize, height: usize, source: impl Fn(Point) -> Point) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |column| Point::new(row, column)))
+            .map(|point| self[source(point)].clone())
+            .collect();
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearranged(self.height, self.width, |p| Point::new(p.column, p.row))
+    }
+
+    /// Mirrors left to right, reversing every row.
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let last = self.width.saturating_sub(1);
+        self.rearranged(self.width, self.height, |p| {
+            Point::new(p.row, last - p.column)
+        })
+    }
+
+    /// Mirrors top to bottom, reversing every column.
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        let last = self.height.saturating_sub(1);
+        self.rearranged(self.width, self.height, |p| {
+            Point::new(last - p.row, p.column)
+        })
+    }
+
+    /// Turns a quarter clockwise: the left column becomes the top row.
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let last = self.height.saturating_sub(1);
+        self.rearranged(self.height, self.width, |p| {
+            Point::new(last - p.column, p.row)
+        })
+    }
+
+    /// Turns a quarter anticlockwise: the right column becomes the top row.
+    pub fn rotate_anticlockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let last = self.width.saturating_sub(1);
+        self.rearranged(self.height, self.width, |p| {
+            Point::new(p.column, last - p.row)
+        })
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point).unwrap_or_else(|| {
+            panic!(
+                "{:?} is outside the {}x{} grid",
+                point, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", point, width, height))
+    }
+}
+
+/// Renders the grid back into lines of text, one row per line.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, row) in self.rows().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            row.iter().try_for_each(|cell| write!(f, "{}", cell))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(input: &str) -> Grid<char> {
+        Grid::parse(input, |_, ch| Ok(ch)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("..S\n^..", |_, ch| match ch {
+            '.' | 'S' | '^' => Ok(ch == '^'),
+            _ => Err(format!("unknown character '{}'", ch)),
+        })
+        .unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert!(grid[Point::new(1, 0)]);
+        assert_eq!(grid.get(Point::new(2, 0)), None);
+
+        let err = Grid::parse("..\n.x", |_, ch| match ch {
+            '.' => Ok(()),
+            _ => Err(format!("unknown character '{}'", ch)),
+        })
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid value at line 2, column 2: unknown character 'x'"
+        );
+
+        let err = Grid::parse("...\n..", |_, ch| Ok::<_, String>(ch)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 2, column 3: row is 2 wide, expected 3"
+        );
+    }
+
+    #[test]
+    fn test_parse_padded() {
+        let grid = Grid::parse_padded("12\n3\n456", ' ', |_, ch| Ok(ch)).unwrap();
+        assert_eq!(grid.to_string(), "12 \n3  \n456");
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = chars(".S.\n..S");
+        assert_eq!(grid.find(|&ch| ch == 'S'), Some(Point::new(0, 1)));
+        assert_eq!(
+            grid.positions(|&ch| ch == 'S').collect::<Vec<_>>(),
+            vec![Point::new(0, 1), Point::new(1, 2)]
+        );
+        assert_eq!(grid.find(|&ch| ch == 'x'), None);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = chars("123\n456\n789");
+        let values = |points: Vec<Point>| points.iter().map(|&p| grid[p]).collect::<String>();
+
+        assert_eq!(values(grid.neighbours4(Point::new(1, 1)).collect()), "2468");
+        assert_eq!(values(grid.neighbours4(Point::new(0, 0)).collect()), "24");
+        assert_eq!(
+            values(grid.neighbours8(Point::new(1, 1)).collect()),
+            "12346789"
+        );
+        assert_eq!(values(grid.neighbours8(Point::new(2, 2)).collect()), "568");
+        assert_eq!(grid.offset(Point::new(0, 2), 0, 1), None);
+    }
+
+    #[test]
+    fn test_slices() {
+        let grid = chars("123\n456");
+        assert_eq!(grid.row(1), Some(&['4', '5', '6'][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.column(1).collect::<String>(), "25");
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(grid.rows().count(), 2);
+    }
+
+    #[test]
+    fn test_index_mut() {
+        let mut grid = chars("..\n..");
+        grid[Point::new(1, 0)] = '#';
+        assert_eq!(grid.to_string(), "..\n#.");
+        assert_eq!(
+            grid.map(|&ch| ch == '#').iter().filter(|&(_, &c)| c).count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_transforms() {
+        let grid = chars("123\n456");
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36");
+        assert_eq!(grid.flip_horizontal().to_string(), "321\n654");
+        assert_eq!(grid.flip_vertical().to_string(), "456\n123");
+        assert_eq!(grid.rotate_clockwise().to_string(), "41\n52\n63");
+        assert_eq!(grid.rotate_anticlockwise().to_string(), "36\n25\n14");
+        assert_eq!(grid.rotate_clockwise().rotate_anticlockwise(), grid);
+
+        let square = chars("123\n456\n789");
+        assert_eq!(square.rotate_anticlockwise().to_string(), "369\n258\n147");
+    }
+
+    #[test]
+    fn test_empty() {
+        let grid = chars("");
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+        assert_eq!(grid.rows().count(), 0);
+        assert_eq!(grid.to_string(), "");
+        assert_eq!(grid.transpose(), grid);
+    }
+}
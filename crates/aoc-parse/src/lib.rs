@@ -0,0 +1,362 @@
+use std::{fmt::Display, ops::RangeInclusive};
+
+use aoc_core::diagnostic::{IResult, NomError};
+use nom::{
+    Parser,
+    bytes::complete::tag,
+    character::complete::{digit1, line_ending, one_of, space0, space1},
+    combinator::{opt, recognize},
+    error::{ErrorKind, context},
+    multi::many1,
+    sequence::{delimited, pair, separated_pair},
+};
+
+/// Error for a run of digits too large for the type it is parsed into. Plain
+/// nom integer parsers report this as a missing number.
+fn out_of_range(input: &str, min: impl Display, max: impl Display) -> nom::Err<NomError<&str>> {
+    nom::Err::Error(NomError {
+        input,
+        kind: ErrorKind::TooLarge,
+        expected: Some(format!("expected a number from {} to {}", min, max)),
+        invalid: None,
+    })
+}
+
+/// An unsigned decimal number.
+pub fn number(input: &str) -> IResult<&str, u64> {
+    let (rest, digits) = digit1(input)?;
+    let value = digits
+        .parse()
+        .map_err(|_| out_of_range(input, u64::MIN, u64::MAX))?;
+    Ok((rest, value))
+}
+
+/// A decimal number with an optional leading `+` or `-`.
+pub fn signed(input: &str) -> IResult<&str, i64> {
+    let (rest, digits) = recognize(pair(opt(one_of("+-")), digit1)).parse(input)?;
+    let value = digits
+        .parse()
+        .map_err(|_| out_of_range(input, i64::MIN, i64::MAX))?;
+    Ok((rest, value))
+}
+
+/// An inclusive `start-end` range, such as `11-22`. A range that ends before
+/// it starts is an invalid value.
+pub fn inclusive_range(input: &str) -> IResult<&str, RangeInclusive<u64>> {
+    let (rest, (start, end)) = separated_pair(
+        context("expected a range start", number),
+        context("expected '-' between range bounds", tag("-")),
+        context("expected a range end", number),
+    )
+    .parse(input)?;
+
+    if end < start {
+        return Err(nom::Err::Failure(NomError::invalid_value(
+            input,
+            format!("range {}-{} ends before it starts", start, end),
+        )));
+    }
+    Ok((rest, start..=end))
+}
+
+/// Lists `options` for an error message, as in `'L', 'R' or 'U'`.
+fn choices(options: &str) -> String {
+    let quoted = options
+        .chars()
+        .map(|c| format!("'{}'", c.escape_default()))
+        .collect::<Vec<_>>();
+    match quoted.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} or {}", rest.join(", "), last),
+        _ => quoted.concat(),
+    }
+}
+
+/// A `value` introduced by one of the characters in `prefixes`, such as the
+/// `L68` or `R30` of a list of turns, returned along with its prefix.
+pub fn prefixed<'a, O, P>(
+    prefixes: &'static str,
+    value: P,
+) -> impl Parser<&'a str, Output = (char, O), Error = NomError<&'a str>>
+where
+    P: Parser<&'a str, Output = O, Error = NomError<&'a str>>,
+{
+    let prefix = move |input: &'a str| {
+        one_of(prefixes).parse(input).map_err(|err| {
+            err.map(|e: NomError<&str>| NomError {
+                expected: Some(format!("expected {}", choices(prefixes))),
+                ..e
+            })
+        })
+    };
+    pair(prefix, value)
+}
+
+/// A single decimal digit.
+pub fn digit(input: &str) -> IResult<&str, u8> {
+    context("expected a digit 0-9", one_of("0123456789"))
+        .map(|d| d.to_digit(10).expect("one_of only accepts digits") as u8)
+        .parse(input)
+}
+
+/// A row of single digits with nothing between them, such as `30373`.
+pub fn digit_row(input: &str) -> IResult<&str, Vec<u8>> {
+    many1(digit).parse(input)
+}
+
+/// One [`digit_row`] per line. Rows are not required to be the same length.
+pub fn digit_grid(input: &str) -> IResult<&str, Vec<Vec<u8>>> {
+    lines(digit_row).parse(input)
+}
+
+/// One or more `item`s separated by `separator`. Once a separator is followed
+/// by something `at_end` does not accept, another item must parse there, so a
+/// bad item is reported with what it expected rather than left behind as
+/// unparsed input.
+fn list<'a, O, P, S, T>(
+    mut item: P,
+    mut separator: S,
+    at_end: fn(&str) -> bool,
+) -> impl Parser<&'a str, Output = Vec<O>, Error = NomError<&'a str>>
+where
+    P: Parser<&'a str, Output = O, Error = NomError<&'a str>>,
+    S: Parser<&'a str, Output = T, Error = NomError<&'a str>>,
+{
+    move |input: &'a str| -> IResult<&'a str, Vec<O>> {
+        let (mut input, first) = item.parse(input)?;
+        let mut items = vec![first];
+
+        while let Ok((rest, _)) = separator.parse(input) {
+            if at_end(rest) {
+                break;
+            }
+            let (rest, next) = item.parse(rest).map_err(|err| match err {
+                nom::Err::Error(e) => nom::Err::Failure(e),
+                err => err,
+            })?;
+            items.push(next);
+            input = rest;
+        }
+        Ok((input, items))
+    }
+}
+
+/// Whether `input` is only whitespace, or starts with a line of nothing but
+/// spaces and tabs.
+fn at_blank_line(input: &str) -> bool {
+    input
+        .trim_start_matches([' ', '\t'])
+        .starts_with(['\n', '\r'])
+        || input.trim().is_empty()
+}
+
+/// One or more `item`s, one per line, up to the end of the input or a blank
+/// line. A line ending after the last item is left for the caller.
+pub fn lines<'a, O, P>(item: P) -> impl Parser<&'a str, Output = Vec<O>, Error = NomError<&'a str>>
+where
+    P: Parser<&'a str, Output = O, Error = NomError<&'a str>>,
+{
+    list(item, line_ending, at_blank_line)
+}
+
+/// One or more `item`s separated by commas, optionally followed by spaces.
+pub fn comma_list<'a, O, P>(
+    item: P,
+) -> impl Parser<&'a str, Output = Vec<O>, Error = NomError<&'a str>>
+where
+    P: Parser<&'a str, Output = O, Error = NomError<&'a str>>,
+{
+    list(item, pair(tag(","), space0), |_| false)
+}
+
+/// `item` with any spaces or tabs around it. Line endings are not skipped.
+pub fn spaced<'a, O, P>(item: P) -> impl Parser<&'a str, Output = O, Error = NomError<&'a str>>
+where
+    P: Parser<&'a str, Output = O, Error = NomError<&'a str>>,
+{
+    delimited(space0, item, space0)
+}
+
+/// One or more `item`s on a single line, separated by spaces or tabs and
+/// possibly indented or followed by trailing blanks.
+pub fn spaced_list<'a, O, P>(
+    item: P,
+) -> impl Parser<&'a str, Output = Vec<O>, Error = NomError<&'a str>>
+where
+    P: Parser<&'a str, Output = O, Error = NomError<&'a str>>,
+{
+    let at_line_end = |rest: &str| rest.is_empty() || rest.starts_with(['\n', '\r']);
+    spaced(list(item, space1, at_line_end))
+}
+
+/// The break between two sections of an input: the end of the last line of
+/// one section and at least one empty line. Lines holding only blanks count
+/// as empty.
+pub fn blank_line(input: &str) -> IResult<&str, &str> {
+    context(
+        "expected a blank line",
+        recognize(pair(line_ending, many1(pair(space0, line_ending)))),
+    )
+    .parse(input)
+}
+
+/// One or more sections parsed by `section`, separated by blank lines.
+pub fn sections<'a, O, P>(
+    section: P,
+) -> impl Parser<&'a str, Output = Vec<O>, Error = NomError<&'a str>>
+where
+    P: Parser<&'a str, Output = O, Error = NomError<&'a str>>,
+{
+    list(section, blank_line, |rest| rest.trim().is_empty())
+}
+
+/// Two differently shaped sections separated by blank lines.
+pub fn section_pair<'a, A, B, P, Q>(
+    first: P,
+    second: Q,
+) -> impl Parser<&'a str, Output = (A, B), Error = NomError<&'a str>>
+where
+    P: Parser<&'a str, Output = A, Error = NomError<&'a str>>,
+    Q: Parser<&'a str, Output = B, Error = NomError<&'a str>>,
+{
+    separated_pair(first, blank_line, second)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::diagnostic::parse_all;
+
+    use super::*;
+
+    fn error<'a, P>(input: &'a str, parser: P) -> String
+    where
+        P: Parser<&'a str, Error = NomError<&'a str>>,
+    {
+        parse_all(input, parser)
+            .err()
+            .expect("parsing should fail")
+            .to_string()
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(number("42 rest"), Ok((" rest", 42)));
+        assert_eq!(signed("-42"), Ok(("", -42)));
+        assert_eq!(signed("+7"), Ok(("", 7)));
+        assert_eq!(
+            error("18446744073709551616", number),
+            "parse error at line 1, column 1: expected a number from 0 to 18446744073709551615"
+        );
+        assert_eq!(
+            error("x", number),
+            "parse error at line 1, column 1: expected a number"
+        );
+        assert_eq!(
+            error("-x", signed),
+            "parse error at line 1, column 2: expected a number"
+        );
+    }
+
+    #[test]
+    fn test_inclusive_range() {
+        assert_eq!(inclusive_range("3-5"), Ok(("", 3..=5)));
+        assert_eq!(
+            error("3-", inclusive_range),
+            "parse error at line 1, column 3: expected a range end"
+        );
+        assert_eq!(
+            error("3+5", inclusive_range),
+            "parse error at line 1, column 2: expected '-' between range bounds"
+        );
+        assert_eq!(
+            error("1-2\n20-10", lines(inclusive_range)),
+            "invalid value at line 2, column 1: range 20-10 ends before it starts"
+        );
+        assert_eq!(inclusive_range("7-7"), Ok(("", 7..=7)));
+    }
+
+    #[test]
+    fn test_prefixed() {
+        let mut turn = prefixed("LR", signed);
+        assert_eq!(turn.parse("L68"), Ok(("", ('L', 68))));
+        assert_eq!(turn.parse("R-3"), Ok(("", ('R', -3))));
+        assert_eq!(
+            error("X5", prefixed("LR", signed)),
+            "parse error at line 1, column 1: expected 'L' or 'R'"
+        );
+        assert_eq!(
+            error("U", prefixed("UDLR", signed)),
+            "parse error at line 1, column 2: expected a number"
+        );
+        assert_eq!(choices("UDLR"), "'U', 'D', 'L' or 'R'");
+        assert_eq!(choices("#"), "'#'");
+    }
+
+    #[test]
+    fn test_digit_grid() {
+        let (_, grid) = digit_grid("303\n255\n65\n").unwrap();
+        assert_eq!(grid, vec![vec![3, 0, 3], vec![2, 5, 5], vec![6, 5]]);
+        assert_eq!(
+            error("30373\n25x12", digit_grid),
+            "parse error at line 2, column 3: unexpected \"x12\", expected end of input"
+        );
+        assert_eq!(
+            error("x", digit_grid),
+            "parse error at line 1, column 1: expected a digit 0-9"
+        );
+    }
+
+    #[test]
+    fn test_lists() {
+        assert_eq!(
+            parse_all("1\r\n2\n3\n", lines(number)).unwrap(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(
+            parse_all("1-2,3-4, 5-6", comma_list(inclusive_range)).unwrap(),
+            vec![1..=2, 3..=4, 5..=6]
+        );
+        assert_eq!(
+            parse_all(" 12  3\t45 ", spaced_list(number)).unwrap(),
+            vec![12, 3, 45]
+        );
+        assert_eq!(
+            error("1\n2\nx\n4", lines(number)),
+            "parse error at line 3, column 1: expected a number"
+        );
+        assert_eq!(
+            error("1-2,3-", comma_list(inclusive_range)),
+            "parse error at line 1, column 7: expected a range end"
+        );
+        assert_eq!(
+            error("1 2 x", spaced_list(number)),
+            "parse error at line 1, column 5: expected a number"
+        );
+        assert_eq!(
+            error("1 2\n3", spaced_list(number)),
+            "parse error at line 2, column 1: unexpected \"3\", expected end of input"
+        );
+    }
+
+    #[test]
+    fn test_sections() {
+        assert_eq!(
+            parse_all("1\n2\n\n3\n \n\n4", sections(lines(number))).unwrap(),
+            vec![vec![1, 2], vec![3], vec![4]]
+        );
+        assert_eq!(
+            parse_all(
+                "1-2\n3-4\n\n5\n6\n",
+                section_pair(lines(inclusive_range), lines(number))
+            )
+            .unwrap(),
+            (vec![1..=2, 3..=4], vec![5, 6])
+        );
+        assert_eq!(
+            error(
+                "1-2\n5",
+                section_pair(lines(inclusive_range), lines(number))
+            ),
+            "parse error at line 2, column 2: expected '-' between range bounds"
+        );
+    }
+}
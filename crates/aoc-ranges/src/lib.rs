@@ -0,0 +1,228 @@
+use std::ops::RangeInclusive;
+
+/// A set of `u64` values stored as sorted inclusive ranges. Ranges are merged
+/// as they are inserted, so no two overlap or touch, which keeps lookups
+/// logarithmic and lengths free of double counting.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeSet {
+    ranges: Vec<(u64, u64)>,
+}
+
+impl RangeSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every value in `range`, merging it with any range it overlaps or
+    /// touches.
+    ///
+    /// Panics if `range` ends before it starts, since such a range is almost
+    /// certainly a bug in the caller rather than a request to add nothing.
+    pub fn insert(&mut self, range: RangeInclusive<u64>) {
+        let (mut start, mut end) = range.into_inner();
+        assert!(
+            start <= end,
+            "range {}..={} ends before it starts",
+            start,
+            end
+        );
+
+        // The ranges from `first` up to `last` overlap or touch the new one
+        let first = self
+            .ranges
+            .partition_point(|&(_, e)| e.saturating_add(1) < start);
+        let last = self
+            .ranges
+            .partition_point(|&(s, _)| s <= end.saturating_add(1));
+
+        if first < last {
+            start = start.min(self.ranges[first].0);
+            end = end.max(self.ranges[last - 1].1);
+        }
+        self.ranges.splice(first..last, [(start, end)]);
+    }
+
+    pub fn contains(&self, value: u64) -> bool {
+        let idx = self.ranges.partition_point(|&(start, _)| start <= value);
+        idx > 0 && self.ranges[idx - 1].1 >= value
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Number of values in the set. The set must not hold every `u64`.
+    pub fn len(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|&(start, end)| end - start + 1)
+            .sum()
+    }
+
+    /// The merged ranges, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<u64>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+
+    /// Every value in the set, in ascending order.
+    pub fn values(&self) -> impl Iterator<Item = u64> + '_ {
+        self.iter().flatten()
+    }
+
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        let mut union = self.clone();
+        union.extend(other.iter());
+        union
+    }
+
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        let mut ranges = Vec::new();
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+
+        while let (Some(&&(a_start, a_end)), Some(&&(b_start, b_end))) = (a.peek(), b.peek()) {
+            let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            // Drop whichever range ends first; the other may overlap more
+            if a_end < b_end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+
+        RangeSet { ranges }
+    }
+
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        self.intersection(&other.complement(0..=u64::MAX))
+    }
+
+    /// The values in `bounds` that are not in the set.
+    pub fn complement(&self, bounds: RangeInclusive<u64>) -> RangeSet {
+        let mut gaps = Vec::new();
+        let mut gap_start = Some(0);
+
+        for &(start, end) in &self.ranges {
+            if let Some(gap) = gap_start
+                && start > gap
+            {
+                gaps.push((gap, start - 1));
+            }
+            gap_start = end.checked_add(1);
+        }
+        if let Some(gap) = gap_start {
+            gaps.push((gap, u64::MAX));
+        }
+
+        RangeSet { ranges: gaps }.intersection(&RangeSet::from_iter([bounds]))
+    }
+}
+
+impl Extend<RangeInclusive<u64>> for RangeSet {
+    fn extend<I: IntoIterator<Item = RangeInclusive<u64>>>(&mut self, ranges: I) {
+        ranges.into_iter().for_each(|range| self.insert(range));
+    }
+}
+
+impl FromIterator<RangeInclusive<u64>> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<u64>>>(ranges: I) -> Self {
+        let mut set = RangeSet::new();
+        set.extend(ranges);
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[RangeInclusive<u64>]) -> RangeSet {
+        ranges.iter().cloned().collect()
+    }
+
+    fn ranges(set: &RangeSet) -> Vec<RangeInclusive<u64>> {
+        set.iter().collect()
+    }
+
+    #[test]
+    fn test_insert() {
+        assert_eq!(
+            ranges(&set(&[1..=3, 5..=7, 9..=11])),
+            vec![1..=3, 5..=7, 9..=11]
+        );
+        assert_eq!(ranges(&set(&[1..=5, 4..=8, 10..=12])), vec![1..=8, 10..=12]);
+        assert_eq!(ranges(&set(&[1..=10, 2..=5, 6..=9])), vec![1..=10]);
+        assert_eq!(ranges(&set(&[1..=3, 4..=6, 7..=9])), vec![1..=9]);
+        assert_eq!(
+            ranges(&set(&[1..=4, 3..=5, 7..=9, 8..=10, 12..=15])),
+            vec![1..=5, 7..=10, 12..=15]
+        );
+        assert_eq!(ranges(&set(&[5..=7, 1..=3, 4..=6])), vec![1..=7]);
+        assert_eq!(ranges(&set(&[10..=12, 1..=2, 4..=20])), vec![1..=2, 4..=20]);
+        assert_eq!(
+            ranges(&set(&[u64::MAX..=u64::MAX, 0..=0])),
+            vec![0..=0, u64::MAX..=u64::MAX]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "range 5..=4 ends before it starts")]
+    fn test_insert_reversed() {
+        #[allow(clippy::reversed_empty_ranges)]
+        RangeSet::new().insert(5..=4);
+    }
+
+    #[test]
+    fn test_contains() {
+        let set = set(&[3..=5, 10..=14, 16..=20]);
+        let contained = (0..=21).filter(|&v| set.contains(v)).collect::<Vec<_>>();
+        assert_eq!(
+            contained,
+            vec![3, 4, 5, 10, 11, 12, 13, 14, 16, 17, 18, 19, 20]
+        );
+        assert!(!RangeSet::new().contains(0));
+    }
+
+    #[test]
+    fn test_len() {
+        assert_eq!(set(&[3..=5, 10..=14, 16..=20, 12..=18]).len(), 14);
+        assert_eq!(RangeSet::new().len(), 0);
+        assert_eq!(
+            set(&[1..=3, 7..=8]).values().collect::<Vec<_>>(),
+            vec![1, 2, 3, 7, 8]
+        );
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[1..=5, 10..=15, 20..=25]);
+        let b = set(&[4..=11, 14..=21]);
+
+        assert_eq!(ranges(&a.union(&b)), vec![1..=25]);
+        assert_eq!(
+            ranges(&a.intersection(&b)),
+            vec![4..=5, 10..=11, 14..=15, 20..=21]
+        );
+        assert_eq!(ranges(&a.difference(&b)), vec![1..=3, 12..=13, 22..=25]);
+        assert_eq!(ranges(&b.difference(&a)), vec![6..=9, 16..=19]);
+        assert_eq!(a.intersection(&RangeSet::new()), RangeSet::new());
+    }
+
+    #[test]
+    fn test_complement() {
+        let a = set(&[3..=5, 10..=15]);
+        assert_eq!(ranges(&a.complement(0..=20)), vec![0..=2, 6..=9, 16..=20]);
+        assert_eq!(ranges(&a.complement(4..=12)), vec![6..=9]);
+        assert_eq!(ranges(&a.complement(3..=5)), vec![]);
+        assert_eq!(ranges(&RangeSet::new().complement(1..=2)), vec![1..=2]);
+        assert_eq!(
+            ranges(&set(&[0..=9, 20..=u64::MAX]).complement(0..=u64::MAX)),
+            vec![10..=19]
+        );
+    }
+}
@@ -0,0 +1,634 @@
+use std::str::FromStr;
+
+use aoc_core::{Part, attempts::Outcome, shrink::Predicate};
+
+pub const USAGE: &str = "\
+Usage:
+    aoc run --day <N> [--part <1|2>] [--input <PATH>] [--format <text|json>]
+    aoc run --all [--part <1|2>] [--format <text|json>] [--jobs <N>]
+    aoc time (--day <N> | --all) [--part <1|2>] [--input <PATH>] [--runs <N>]
+    aoc verify [--day <N>] [--answers <PATH>]
+    aoc attempt --day <N> --part <1|2> --answer <A> --outcome <OUTCOME> [--input <PATH>]
+    aoc new <N>
+    aoc gen --day <N> [--seed <SEED>] [--size <N>]
+    aoc shrink --day <N> (--panic | --mismatch | --answer <A>) [--part <1|2>] [--input <PATH>]
+    aoc trace --day <N> [--input <PATH>] [--format <table|csv|json>]
+    aoc help
+
+Options:
+    -d, --day <N>         Day to run
+    -a, --all             Run every registered day
+    -p, --part <1|2>      Only run the given part
+    -i, --input <PATH>    Input file, `-` for stdin (defaults to inputs/dayNN.txt)
+    -n, --runs <N>        Times to repeat each day when timing (default 1)
+    -f, --format <FMT>    Output `text` (default) or one JSON object per answer
+    -j, --jobs <N>        Days to run at once (defaults to the number of cores)
+        --answers <PATH>  Known answers to verify against (defaults to inputs/answers.txt)
+    -s, --seed <SEED>     Seed for generated inputs (default 0)
+        --size <N>        Records in a generated input (default 100)
+        --outcome <OUTCOME>
+                          Verdict on a submitted answer: correct, too-high, too-low or wrong
+        --panic           Shrink to an input on which the solver panics
+        --mismatch        Shrink to an input on which the solver disagrees with its reference
+        --answer <A>      Shrink to an input on which some part answers <A>";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run(RunArgs),
+    Time(RunArgs),
+    Verify(VerifyArgs),
+    Attempt(AttemptArgs),
+    New(u8),
+    Generate(GenerateArgs),
+    Shrink(ShrinkArgs),
+    Trace(TraceArgs),
+    Help,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum DaySelection {
+    One(u8),
+    All,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("invalid format `{}`, expected text or json", s)),
+        }
+    }
+}
+
+/// How `aoc trace` prints a trace.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TraceFormat {
+    #[default]
+    Table,
+    Csv,
+    Json,
+}
+
+impl FromStr for TraceFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(TraceFormat::Table),
+            "csv" => Ok(TraceFormat::Csv),
+            "json" => Ok(TraceFormat::Json),
+            _ => Err(format!(
+                "invalid trace format `{}`, expected table, csv or json",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RunArgs {
+    pub days: DaySelection,
+    pub part: Option<Part>,
+    pub input: Option<String>,
+    pub runs: usize,
+    pub format: Format,
+    /// Worker threads for running days in parallel; `None` picks one per core.
+    pub jobs: Option<usize>,
+}
+
+impl RunArgs {
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct VerifyArgs {
+    pub days: DaySelection,
+    pub answers: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct AttemptArgs {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub outcome: Outcome,
+    pub input: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct GenerateArgs {
+    pub day: u8,
+    pub seed: u64,
+    pub size: usize,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ShrinkArgs {
+    pub day: u8,
+    pub predicate: Predicate,
+    pub part: Option<Part>,
+    pub input: Option<String>,
+}
+
+impl ShrinkArgs {
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct TraceArgs {
+    pub day: u8,
+    pub input: Option<String>,
+    pub format: TraceFormat,
+}
+
+fn value<'a>(flag: &str, args: &mut impl Iterator<Item = &'a String>) -> Result<&'a str, String> {
+    args.next()
+        .map(String::as_str)
+        .ok_or_else(|| format!("missing value for `{}`", flag))
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    match value.parse::<u8>() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("invalid day `{}`, expected 1-25", value)),
+    }
+}
+
+fn parse_count(value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(count) if count > 0 => Ok(count),
+        _ => Err(format!(
+            "invalid count `{}`, expected a positive number",
+            value
+        )),
+    }
+}
+
+fn parse_run(command: &str, args: &[String]) -> Result<RunArgs, String> {
+    let mut day = None;
+    let mut all = false;
+    let mut part = None;
+    let mut input = None;
+    let mut runs = 1;
+    let mut format = Format::default();
+    let mut jobs = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            flag @ ("-d" | "--day") => day = Some(parse_day(value(flag, &mut args)?)?),
+            "-a" | "--all" => all = true,
+            flag @ ("-p" | "--part") => part = Some(value(flag, &mut args)?.parse()?),
+            flag @ ("-i" | "--input") => input = Some(value(flag, &mut args)?.to_string()),
+            flag @ ("-n" | "--runs") if command == "time" => {
+                runs = parse_count(value(flag, &mut args)?)?
+            }
+            flag @ ("-f" | "--format") if command == "run" => {
+                format = value(flag, &mut args)?.parse()?
+            }
+            flag @ ("-j" | "--jobs") if command == "run" => {
+                jobs = Some(parse_count(value(flag, &mut args)?)?)
+            }
+            other => return Err(format!("unexpected argument `{}`", other)),
+        }
+    }
+
+    let days = match (day, all) {
+        (Some(day), false) => DaySelection::One(day),
+        (None, true) => DaySelection::All,
+        (Some(_), true) => return Err("`--day` cannot be combined with `--all`".to_string()),
+        (None, false) => return Err("expected `--day <N>` or `--all`".to_string()),
+    };
+
+    if days == DaySelection::All && input.is_some() {
+        return Err("`--input` cannot be combined with `--all`".to_string());
+    }
+
+    Ok(RunArgs {
+        days,
+        part,
+        input,
+        runs,
+        format,
+        jobs,
+    })
+}
+
+fn parse_verify(args: &[String]) -> Result<VerifyArgs, String> {
+    let mut days = DaySelection::All;
+    let mut answers = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            flag @ ("-d" | "--day") => {
+                days = DaySelection::One(parse_day(value(flag, &mut args)?)?)
+            }
+            "-a" | "--all" => days = DaySelection::All,
+            flag @ "--answers" => answers = Some(value(flag, &mut args)?.to_string()),
+            other => return Err(format!("unexpected argument `{}`", other)),
+        }
+    }
+
+    Ok(VerifyArgs { days, answers })
+}
+
+/// An answer as written to the attempts log, where fields are separated by
+/// whitespace.
+fn parse_answer(value: &str) -> Result<String, String> {
+    if value.is_empty() || value.contains(char::is_whitespace) {
+        return Err(format!(
+            "invalid answer `{}`, expected an answer without whitespace",
+            value.escape_debug()
+        ));
+    }
+    Ok(value.to_string())
+}
+
+fn parse_attempt(args: &[String]) -> Result<AttemptArgs, String> {
+    let mut day = None;
+    let mut part = None;
+    let mut answer = None;
+    let mut outcome = None;
+    let mut input = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            flag @ ("-d" | "--day") => day = Some(parse_day(value(flag, &mut args)?)?),
+            flag @ ("-p" | "--part") => part = Some(value(flag, &mut args)?.parse()?),
+            flag @ "--answer" => answer = Some(parse_answer(value(flag, &mut args)?)?),
+            flag @ "--outcome" => outcome = Some(value(flag, &mut args)?.parse()?),
+            flag @ ("-i" | "--input") => input = Some(value(flag, &mut args)?.to_string()),
+            other => return Err(format!("unexpected argument `{}`", other)),
+        }
+    }
+
+    Ok(AttemptArgs {
+        day: day.ok_or_else(|| "expected `--day <N>`".to_string())?,
+        part: part.ok_or_else(|| "expected `--part <1|2>`".to_string())?,
+        answer: answer.ok_or_else(|| "expected `--answer <A>`".to_string())?,
+        outcome: outcome.ok_or_else(|| "expected `--outcome <OUTCOME>`".to_string())?,
+        input,
+    })
+}
+
+fn parse_generate(args: &[String]) -> Result<GenerateArgs, String> {
+    let mut day = None;
+    let mut seed = 0;
+    let mut size = 100;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            flag @ ("-d" | "--day") => day = Some(parse_day(value(flag, &mut args)?)?),
+            flag @ ("-s" | "--seed") => {
+                let value = value(flag, &mut args)?;
+                seed = value
+                    .parse()
+                    .map_err(|_| format!("invalid seed `{}`, expected a number", value))?
+            }
+            flag @ "--size" => size = parse_count(value(flag, &mut args)?)?,
+            other => return Err(format!("unexpected argument `{}`", other)),
+        }
+    }
+
+    Ok(GenerateArgs {
+        day: day.ok_or_else(|| "expected `--day <N>`".to_string())?,
+        seed,
+        size,
+    })
+}
+
+fn parse_shrink(args: &[String]) -> Result<ShrinkArgs, String> {
+    let mut day = None;
+    let mut predicates = Vec::new();
+    let mut part = None;
+    let mut input = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            flag @ ("-d" | "--day") => day = Some(parse_day(value(flag, &mut args)?)?),
+            flag @ ("-p" | "--part") => part = Some(value(flag, &mut args)?.parse()?),
+            flag @ ("-i" | "--input") => input = Some(value(flag, &mut args)?.to_string()),
+            "--panic" => predicates.push(Predicate::Panic),
+            "--mismatch" => predicates.push(Predicate::Mismatch),
+            flag @ "--answer" => {
+                predicates.push(Predicate::Answer(value(flag, &mut args)?.to_string()))
+            }
+            other => return Err(format!("unexpected argument `{}`", other)),
+        }
+    }
+
+    let predicate = match <[Predicate; 1]>::try_from(predicates) {
+        Ok([predicate]) => predicate,
+        Err(_) => {
+            return Err("expected one of `--panic`, `--mismatch` or `--answer <A>`".to_string());
+        }
+    };
+
+    Ok(ShrinkArgs {
+        day: day.ok_or_else(|| "expected `--day <N>`".to_string())?,
+        predicate,
+        part,
+        input,
+    })
+}
+
+fn parse_trace(args: &[String]) -> Result<TraceArgs, String> {
+    let mut day = None;
+    let mut input = None;
+    let mut format = TraceFormat::default();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            flag @ ("-d" | "--day") => day = Some(parse_day(value(flag, &mut args)?)?),
+            flag @ ("-i" | "--input") => input = Some(value(flag, &mut args)?.to_string()),
+            flag @ ("-f" | "--format") => format = value(flag, &mut args)?.parse()?,
+            other => return Err(format!("unexpected argument `{}`", other)),
+        }
+    }
+
+    Ok(TraceArgs {
+        day: day.ok_or_else(|| "expected `--day <N>`".to_string())?,
+        input,
+        format,
+    })
+}
+
+pub fn parse(args: &[String]) -> Result<Command, String> {
+    match args.split_first() {
+        Some((command, rest)) => match command.as_str() {
+            "run" => parse_run(command, rest).map(Command::Run),
+            "time" => parse_run(command, rest).map(Command::Time),
+            "verify" => parse_verify(rest).map(Command::Verify),
+            "attempt" => parse_attempt(rest).map(Command::Attempt),
+            "gen" => parse_generate(rest).map(Command::Generate),
+            "shrink" => parse_shrink(rest).map(Command::Shrink),
+            "trace" => parse_trace(rest).map(Command::Trace),
+            "new" => match rest {
+                [day] => parse_day(day).map(Command::New),
+                _ => Err("expected `new <N>`".to_string()),
+            },
+            "help" | "-h" | "--help" => Ok(Command::Help),
+            other => Err(format!("unknown command `{}`", other)),
+        },
+        None => Ok(Command::Help),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_run_day() {
+        assert_eq!(
+            parse(&args("run --day 7 --part 2 --input foo.txt")),
+            Ok(Command::Run(RunArgs {
+                days: DaySelection::One(7),
+                part: Some(Part::Two),
+                input: Some("foo.txt".to_string()),
+                runs: 1,
+                format: Format::Text,
+                jobs: None,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_run_all() {
+        assert_eq!(
+            parse(&args("run -a")),
+            Ok(Command::Run(RunArgs {
+                days: DaySelection::All,
+                part: None,
+                input: None,
+                runs: 1,
+                format: Format::Text,
+                jobs: None,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(
+            parse(&args("run --all --format json")),
+            Ok(Command::Run(RunArgs {
+                days: DaySelection::All,
+                part: None,
+                input: None,
+                runs: 1,
+                format: Format::Json,
+                jobs: None,
+            }))
+        );
+        assert!(parse(&args("run --all --format yaml")).is_err());
+        assert!(parse(&args("time --all --format json")).is_err());
+    }
+
+    #[test]
+    fn test_jobs() {
+        assert_eq!(
+            parse(&args("run --all -j 2")),
+            Ok(Command::Run(RunArgs {
+                days: DaySelection::All,
+                part: None,
+                input: None,
+                runs: 1,
+                format: Format::Text,
+                jobs: Some(2),
+            }))
+        );
+        assert!(parse(&args("run --all --jobs 0")).is_err());
+        assert!(parse(&args("time --all --jobs 2")).is_err());
+    }
+
+    #[test]
+    fn test_time() {
+        assert_eq!(
+            parse(&args("time --all --runs 10")),
+            Ok(Command::Time(RunArgs {
+                days: DaySelection::All,
+                part: None,
+                input: None,
+                runs: 10,
+                format: Format::Text,
+                jobs: None,
+            }))
+        );
+        assert!(parse(&args("time --all --runs 0")).is_err());
+        assert!(parse(&args("run --all --runs 10")).is_err());
+    }
+
+    #[test]
+    fn test_invalid_run() {
+        assert!(parse(&args("run")).is_err());
+        assert!(parse(&args("run --day 0")).is_err());
+        assert!(parse(&args("run --day 26")).is_err());
+        assert!(parse(&args("run --day")).is_err());
+        assert!(parse(&args("run --day 1 --all")).is_err());
+        assert!(parse(&args("run --all --input foo.txt")).is_err());
+        assert!(parse(&args("run --day 1 --part 3")).is_err());
+        assert!(parse(&args("fly")).is_err());
+    }
+
+    #[test]
+    fn test_verify() {
+        assert_eq!(
+            parse(&args("verify")),
+            Ok(Command::Verify(VerifyArgs {
+                days: DaySelection::All,
+                answers: None,
+            }))
+        );
+        assert_eq!(
+            parse(&args("verify --day 3 --answers known.txt")),
+            Ok(Command::Verify(VerifyArgs {
+                days: DaySelection::One(3),
+                answers: Some("known.txt".to_string()),
+            }))
+        );
+        assert!(parse(&args("verify --part 1")).is_err());
+        assert!(parse(&args("verify --answers")).is_err());
+    }
+
+    #[test]
+    fn test_attempt() {
+        assert_eq!(
+            parse(&args(
+                "attempt --day 1 --part 2 --answer 6379 --outcome too-low"
+            )),
+            Ok(Command::Attempt(AttemptArgs {
+                day: 1,
+                part: Part::Two,
+                answer: "6379".to_string(),
+                outcome: Outcome::TooLow,
+                input: None,
+            }))
+        );
+        assert!(parse(&args("attempt --day 1 --part 2 --answer 6379")).is_err());
+        assert!(parse(&args("attempt --day 1 --answer 6379 --outcome wrong")).is_err());
+        assert!(parse(&args("attempt --day 1 --part 2 --answer 1 --outcome low")).is_err());
+
+        let mut spaced = args("attempt --day 1 --part 2 --outcome wrong --answer");
+        spaced.push("12 34".to_string());
+        assert_eq!(
+            parse(&spaced),
+            Err("invalid answer `12 34`, expected an answer without whitespace".to_string())
+        );
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(parse(&args("new 8")), Ok(Command::New(8)));
+        assert!(parse(&args("new")).is_err());
+        assert!(parse(&args("new 26")).is_err());
+        assert!(parse(&args("new 8 9")).is_err());
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(
+            parse(&args("gen --day 3")),
+            Ok(Command::Generate(GenerateArgs {
+                day: 3,
+                seed: 0,
+                size: 100,
+            }))
+        );
+        assert_eq!(
+            parse(&args("gen -d 7 --seed 42 --size 1000")),
+            Ok(Command::Generate(GenerateArgs {
+                day: 7,
+                seed: 42,
+                size: 1000,
+            }))
+        );
+        assert!(parse(&args("gen")).is_err());
+        assert!(parse(&args("gen --day 3 --seed x")).is_err());
+        assert!(parse(&args("gen --day 3 --size 0")).is_err());
+    }
+
+    #[test]
+    fn test_shrink() {
+        assert_eq!(
+            parse(&args("shrink --day 6 --mismatch")),
+            Ok(Command::Shrink(ShrinkArgs {
+                day: 6,
+                predicate: Predicate::Mismatch,
+                part: None,
+                input: None,
+            }))
+        );
+        assert_eq!(
+            parse(&args("shrink -d 1 --answer 1076 --part 1 --input big.txt")),
+            Ok(Command::Shrink(ShrinkArgs {
+                day: 1,
+                predicate: Predicate::Answer("1076".to_string()),
+                part: Some(Part::One),
+                input: Some("big.txt".to_string()),
+            }))
+        );
+        assert!(parse(&args("shrink --day 6")).is_err());
+        assert!(parse(&args("shrink --panic")).is_err());
+        assert!(parse(&args("shrink --day 6 --panic --mismatch")).is_err());
+        assert!(parse(&args("shrink --day 6 --answer")).is_err());
+    }
+
+    #[test]
+    fn test_trace() {
+        assert_eq!(
+            parse(&args("trace --day 1")),
+            Ok(Command::Trace(TraceArgs {
+                day: 1,
+                input: None,
+                format: TraceFormat::Table,
+            }))
+        );
+        assert_eq!(
+            parse(&args("trace -d 1 -i small.txt --format csv")),
+            Ok(Command::Trace(TraceArgs {
+                day: 1,
+                input: Some("small.txt".to_string()),
+                format: TraceFormat::Csv,
+            }))
+        );
+        assert!(parse(&args("trace")).is_err());
+        assert!(parse(&args("trace --day 1 --format text")).is_err());
+        assert!(parse(&args("trace --day 1 --part 1")).is_err());
+    }
+
+    #[test]
+    fn test_help() {
+        assert_eq!(parse(&[]), Ok(Command::Help));
+        assert_eq!(parse(&args("--help")), Ok(Command::Help));
+    }
+}
@@ -0,0 +1,30 @@
+use aoc_core::{Day, Solver};
+
+pub static ALL: &[&dyn Day] = &[
+    &Solver::<day01::Day01>::new(),
+    &Solver::<day02::Day02>::new(),
+    &Solver::<day03::Day03>::new(),
+    &Solver::<day05::Day05>::new(),
+    &Solver::<day06::Day06>::new(),
+    &Solver::<day07::Day07>::new(),
+];
+
+pub fn find(day: u8) -> Option<&'static dyn Day> {
+    ALL.iter().copied().find(|d| d.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_are_sorted_and_unique() {
+        assert!(ALL.windows(2).all(|w| w[0].day() < w[1].day()));
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(7).map(|d| d.day()), Some(7));
+        assert!(find(0).is_none());
+    }
+}
@@ -0,0 +1,177 @@
+use std::fmt::Write;
+
+use aoc_core::{Answer, PartAnswer, Run, Trace, input::InputSource};
+
+/// Where a run's input came from, so results on different inputs can be told
+/// apart downstream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputIdentity {
+    pub source: String,
+    pub hash: String,
+}
+
+impl InputIdentity {
+    pub fn new(source: &InputSource, input: &str) -> Self {
+        InputIdentity {
+            source: source.to_string(),
+            hash: aoc_core::input::hash(input),
+        }
+    }
+}
+
+/// Quotes `s` as a JSON string.
+pub fn string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn input_object(input: &InputIdentity) -> String {
+    format!(
+        "{{\"source\":{},\"hash\":{}}}",
+        string(&input.source),
+        string(&input.hash)
+    )
+}
+
+/// Serializes an answer, or a trace cell: numbers as JSON numbers and text
+/// as strings. Consumers reading into doubles should check `answer_type`
+/// before trusting numbers above 2^53.
+pub fn answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Number(n) => n.to_string(),
+        Answer::Text(s) => string(s),
+    }
+}
+
+/// One JSON line for an answer, next to the type it was computed as.
+pub fn answer_line(answer: &PartAnswer, run: &Run, input: &InputIdentity) -> String {
+    format!(
+        "{{\"day\":{},\"part\":{},\"answer\":{},\"answer_type\":{},\"parse_ns\":{},\"solve_ns\":{},\"input\":{}}}",
+        answer.day,
+        answer.part,
+        self::answer(&answer.answer),
+        string(answer.answer_type),
+        run.parse.as_nanos(),
+        answer.elapsed.as_nanos(),
+        input_object(input)
+    )
+}
+
+/// One JSON line for a day that failed to load, parse or solve.
+pub fn error_line(day: u8, error: &str, input: Option<&InputIdentity>) -> String {
+    let input = match input {
+        Some(input) => input_object(input),
+        None => "null".to_string(),
+    };
+    format!(
+        "{{\"day\":{},\"error\":{},\"input\":{}}}",
+        day,
+        string(error),
+        input
+    )
+}
+
+/// One JSON object per row of a trace, keyed by column name. Cells are
+/// written like answers.
+pub fn trace_lines(trace: &Trace) -> Vec<String> {
+    trace
+        .rows()
+        .iter()
+        .map(|row| {
+            let fields = trace
+                .columns()
+                .iter()
+                .zip(row)
+                .map(|(column, value)| format!("{}:{}", string(column), answer(value)))
+                .collect::<Vec<_>>();
+            format!("{{{}}}", fields.join(","))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::PathBuf, time::Duration};
+
+    use aoc_core::Part;
+
+    use super::*;
+
+    #[test]
+    fn test_string() {
+        assert_eq!(string("357"), "\"357\"");
+        assert_eq!(
+            string("say \"hi\"\\\n\tµs\u{1}"),
+            "\"say \\\"hi\\\"\\\\\\n\\tµs\\u0001\""
+        );
+    }
+
+    #[test]
+    fn test_answer() {
+        assert_eq!(answer(&Answer::from(u64::MAX)), "18446744073709551615");
+        assert_eq!(answer(&Answer::from(-3_i64)), "-3");
+        assert_eq!(answer(&Answer::from("AB\"C")), "\"AB\\\"C\"");
+    }
+
+    #[test]
+    fn test_answer_line() {
+        let answer = PartAnswer {
+            day: 3,
+            part: Part::One,
+            answer: Answer::Number(357),
+            answer_type: "u64",
+            elapsed: Duration::from_nanos(1500),
+        };
+        let run = Run {
+            day: 3,
+            parse: Duration::from_nanos(2000),
+            answers: vec![answer.clone()],
+        };
+        let input = InputIdentity::new(&InputSource::Path(PathBuf::from("inputs/day03.txt")), "");
+
+        assert_eq!(
+            answer_line(&answer, &run, &input),
+            "{\"day\":3,\"part\":1,\"answer\":357,\"answer_type\":\"u64\",\
+             \"parse_ns\":2000,\"solve_ns\":1500,\
+             \"input\":{\"source\":\"inputs/day03.txt\",\"hash\":\"cbf29ce484222325\"}}"
+        );
+    }
+
+    #[test]
+    fn test_trace_lines() {
+        let mut trace = Trace::new(&["step", "rotation", "landed"]);
+        trace.push(vec![1.into(), "L68".into(), 0.into()]);
+        trace.push(vec![2.into(), "R\"".into(), 1.into()]);
+
+        assert_eq!(
+            trace_lines(&trace),
+            vec![
+                "{\"step\":1,\"rotation\":\"L68\",\"landed\":0}",
+                "{\"step\":2,\"rotation\":\"R\\\"\",\"landed\":1}",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_error_line() {
+        assert_eq!(
+            error_line(7, "no start", None),
+            "{\"day\":7,\"error\":\"no start\",\"input\":null}"
+        );
+    }
+}
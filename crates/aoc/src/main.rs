@@ -0,0 +1,425 @@
+use std::{
+    env, panic,
+    path::Path,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use aoc_core::{
+    Day, Part, Run,
+    answers::{Answers, Verdict},
+    attempts::{Attempt, Attempts},
+    input::{self, InputSource},
+    shrink,
+};
+use cli::{
+    AttemptArgs, Command, DaySelection, Format, GenerateArgs, RunArgs, ShrinkArgs, TraceArgs,
+    TraceFormat, VerifyArgs,
+};
+use json::InputIdentity;
+use timing::DayTiming;
+
+mod cli;
+mod days;
+mod json;
+mod pool;
+mod scaffold;
+mod timing;
+
+fn load_source(day: &dyn Day, input: Option<&str>) -> Result<(InputSource, String), String> {
+    input::resolve(day.day(), input, day.embedded_input())
+        .and_then(|source| source.read().map(|input| (source, input)))
+        .map_err(|e| e.to_string())
+}
+
+fn load(day: &dyn Day, input: Option<&str>) -> Result<String, String> {
+    load_source(day, input).map(|(_, input)| input)
+}
+
+fn select(days: &DaySelection) -> Option<Vec<&'static dyn Day>> {
+    match *days {
+        DaySelection::One(day) => match days::find(day) {
+            Some(day) => Some(vec![day]),
+            None => {
+                eprintln!("error: day {:02} is not implemented", day);
+                None
+            }
+        },
+        DaySelection::All => Some(days::ALL.to_vec()),
+    }
+}
+
+fn exit_code(failed: bool) -> ExitCode {
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Output of running one day, collected on a worker thread so days can run in
+/// parallel and still print in order.
+#[derive(Default)]
+struct DayReport {
+    stdout: Vec<String>,
+    stderr: Vec<String>,
+    failed: bool,
+    /// Time the day kept its worker busy, from loading the input to the last
+    /// answer.
+    busy: Duration,
+}
+
+/// Runs one day, printing answers as text or one JSON line each, and warns
+/// about answers the attempt log says were already rejected.
+fn run_day(day: &dyn Day, args: &RunArgs, parts: &[Part], attempts: &Attempts) -> DayReport {
+    let start = Instant::now();
+    let mut report = DayReport::default();
+
+    let (source, input) = match load_source(day, args.input.as_deref()) {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            match args.format {
+                Format::Text => report.stderr.push(format!("Day {:02}: {}", day.day(), e)),
+                Format::Json => report.stdout.push(json::error_line(day.day(), &e, None)),
+            }
+            report.failed = true;
+            report.busy = start.elapsed();
+            return report;
+        }
+    };
+
+    let identity = InputIdentity::new(&source, &input);
+    match day.run(&input, parts) {
+        Ok(run) => {
+            for answer in &run.answers {
+                report.stdout.push(match args.format {
+                    Format::Text => answer.to_string(),
+                    Format::Json => json::answer_line(answer, &run, &identity),
+                });
+                if let Some(warning) = attempts.check(answer, &identity.hash) {
+                    report
+                        .stderr
+                        .push(format!("warning: {} {}", answer, warning));
+                }
+            }
+        }
+        Err(e) => {
+            match args.format {
+                Format::Text => report.stderr.push(format!(
+                    "Day {:02}: {}",
+                    day.day(),
+                    day.render_error(&e, &input)
+                )),
+                Format::Json => {
+                    report
+                        .stdout
+                        .push(json::error_line(day.day(), &e.to_string(), Some(&identity)))
+                }
+            }
+            report.failed = true;
+        }
+    }
+
+    report.busy = start.elapsed();
+    report
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    let Some(selected) = select(&args.days) else {
+        return ExitCode::FAILURE;
+    };
+
+    // The log only feeds warnings, so a broken one must not stop the run
+    let attempts = Attempts::load_default().unwrap_or_else(|e| {
+        eprintln!("warning: ignoring attempts log: {}", e);
+        Attempts::default()
+    });
+
+    let parts = args.parts();
+    let jobs = args.jobs.unwrap_or_else(pool::default_threads);
+
+    let start = Instant::now();
+    let reports = pool::map(&selected, jobs, |&day| {
+        run_day(day, &args, &parts, &attempts)
+    });
+    let wall = start.elapsed();
+
+    let mut failed = false;
+    for report in &reports {
+        report.stdout.iter().for_each(|line| println!("{}", line));
+        report.stderr.iter().for_each(|line| eprintln!("{}", line));
+        failed |= report.failed;
+    }
+
+    // JSON output stays one object per line, so only text gets the summary
+    if args.format == Format::Text && reports.len() > 1 {
+        let summed = reports.iter().map(|report| report.busy).sum();
+        let threads = jobs.min(reports.len());
+        println!(
+            "\n{} days in {} wall time, {} summed day time on {} thread{}",
+            reports.len(),
+            timing::format_duration(wall),
+            timing::format_duration(summed),
+            threads,
+            if threads == 1 { "" } else { "s" }
+        );
+    }
+
+    exit_code(failed)
+}
+
+fn time(args: RunArgs) -> ExitCode {
+    let Some(selected) = select(&args.days) else {
+        return ExitCode::FAILURE;
+    };
+
+    let parts = args.parts();
+    let mut timings = Vec::new();
+    let mut failed = false;
+
+    for day in selected {
+        let runs = load(day, args.input.as_deref()).and_then(|input| {
+            (0..args.runs)
+                .map(|_| {
+                    day.run(&input, &parts)
+                        .map_err(|e| day.render_error(&e, &input))
+                })
+                .collect::<Result<Vec<Run>, String>>()
+        });
+
+        match runs {
+            Ok(runs) => timings.push(DayTiming::from_runs(&runs)),
+            Err(e) => {
+                eprintln!("Day {:02}: {}", day.day(), e);
+                failed = true;
+            }
+        }
+    }
+
+    println!("{}", timing::table(&timings));
+    exit_code(failed)
+}
+
+fn verify(args: VerifyArgs) -> ExitCode {
+    let Some(selected) = select(&args.days) else {
+        return ExitCode::FAILURE;
+    };
+
+    let answers = match &args.answers {
+        Some(path) => Answers::load(Path::new(path)),
+        None => Answers::load_default(),
+    };
+    let answers = match answers {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+
+    for day in selected {
+        let result = load(day, None).and_then(|input| {
+            day.solve(&input, &Part::ALL)
+                .map(|given| (input::hash(&input), given))
+                .map_err(|e| day.render_error(&e, &input))
+        });
+
+        let (hash, given) = match result {
+            Ok(result) => result,
+            Err(e) => {
+                eprintln!("Day {:02}: {}", day.day(), e);
+                failed += 1;
+                continue;
+            }
+        };
+
+        for answer in given {
+            let verdict = answers.check(&answer, &hash);
+            match verdict {
+                Verdict::Pass => passed += 1,
+                Verdict::Fail { .. } => failed += 1,
+                Verdict::Unknown => unknown += 1,
+            }
+            println!("{}  {}", answer, verdict);
+        }
+    }
+
+    println!(
+        "\n{} passed, {} failed, {} unknown",
+        passed, failed, unknown
+    );
+    exit_code(failed > 0)
+}
+
+/// Records a submitted answer in the attempt log, tied to the input it was
+/// computed from.
+fn attempt(args: AttemptArgs) -> ExitCode {
+    let Some(selected) = select(&DaySelection::One(args.day)) else {
+        return ExitCode::FAILURE;
+    };
+
+    let input = match load(selected[0], args.input.as_deref()) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let attempt = Attempt {
+        day: args.day,
+        part: args.part,
+        answer: args.answer,
+        outcome: args.outcome,
+        input_hash: Some(input::hash(&input)),
+    };
+    let path = Attempts::default_path();
+    match Attempts::append(&path, &attempt) {
+        Ok(()) => {
+            println!("recorded `{}` in {}", attempt, path.display());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {}: {}", path.display(), e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn generate(args: GenerateArgs) -> ExitCode {
+    let Some(selected) = select(&DaySelection::One(args.day)) else {
+        return ExitCode::FAILURE;
+    };
+
+    match selected[0].generate(args.seed, args.size) {
+        Some(input) => {
+            print!("{}", input);
+            ExitCode::SUCCESS
+        }
+        None => {
+            eprintln!("error: day {:02} has no input generator", args.day);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn shrink(args: ShrinkArgs) -> ExitCode {
+    let Some(selected) = select(&DaySelection::One(args.day)) else {
+        return ExitCode::FAILURE;
+    };
+    let day = selected[0];
+
+    let input = match load(day, args.input.as_deref()) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // Every candidate that panics would otherwise print a message
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let shrunk = shrink::minimize(day, &input, &args.predicate, &args.parts());
+    panic::set_hook(hook);
+
+    match shrunk {
+        Some(shrunk) => {
+            print!("{}", shrunk.input);
+            eprintln!(
+                "shrunk {} lines to {} in {} attempts",
+                input.lines().count(),
+                shrunk.input.lines().count(),
+                shrunk.attempts
+            );
+            ExitCode::SUCCESS
+        }
+        None => {
+            eprintln!(
+                "error: day {:02} does not show {} on this input",
+                args.day, args.predicate
+            );
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn trace(args: TraceArgs) -> ExitCode {
+    let Some(selected) = select(&DaySelection::One(args.day)) else {
+        return ExitCode::FAILURE;
+    };
+    let day = selected[0];
+
+    let input = match load(day, args.input.as_deref()) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match day.trace(&input) {
+        Ok(Some(trace)) => {
+            match args.format {
+                TraceFormat::Table => print!("{}", trace),
+                TraceFormat::Csv => print!("{}", trace.to_csv()),
+                TraceFormat::Json => json::trace_lines(&trace)
+                    .iter()
+                    .for_each(|line| println!("{}", line)),
+            }
+            ExitCode::SUCCESS
+        }
+        Ok(None) => {
+            eprintln!("error: day {:02} has no trace", args.day);
+            ExitCode::FAILURE
+        }
+        Err(e) => {
+            eprintln!("error: {}", day.render_error(&e, &input));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn new_day(day: u8) -> ExitCode {
+    let created = scaffold::workspace_root()
+        .map_err(|e| e.to_string())
+        .and_then(|root| scaffold::create(&root, day));
+
+    match created {
+        Ok(paths) => {
+            paths
+                .iter()
+                .for_each(|path| println!("wrote {}", path.display()));
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+
+    match cli::parse(&args) {
+        Ok(Command::Run(args)) => run(args),
+        Ok(Command::Time(args)) => time(args),
+        Ok(Command::Verify(args)) => verify(args),
+        Ok(Command::Attempt(args)) => attempt(args),
+        Ok(Command::New(day)) => new_day(day),
+        Ok(Command::Generate(args)) => generate(args),
+        Ok(Command::Shrink(args)) => shrink(args),
+        Ok(Command::Trace(args)) => trace(args),
+        Ok(Command::Help) => {
+            println!("{}", cli::USAGE);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, cli::USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
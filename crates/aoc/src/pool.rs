@@ -0,0 +1,75 @@
+use std::{
+    num::NonZeroUsize,
+    sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread,
+};
+
+/// Worker threads to use when none are asked for: one per available core.
+pub fn default_threads() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+/// Calls `f` on every item from up to `threads` worker threads and returns the
+/// results in the order of `items`. Each worker takes the next unclaimed item
+/// when it finishes one, so a slow item does not hold up the rest.
+pub fn map<T, R, F>(items: &[T], threads: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new((0..items.len()).map(|_| None).collect::<Vec<_>>());
+
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, items.len().max(1)) {
+            scope.spawn(|| {
+                loop {
+                    let idx = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(idx) else {
+                        break;
+                    };
+
+                    let result = f(item);
+                    results.lock().expect("a worker panicked")[idx] = Some(result);
+                }
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .expect("a worker panicked")
+        .into_iter()
+        .map(|result| result.expect("every item should have been processed"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_map_keeps_order() {
+        let items = (0..20).collect::<Vec<u64>>();
+        let squares = map(&items, 4, |&n| {
+            // Make early items finish last
+            thread::sleep(Duration::from_millis(20 - n));
+            n * n
+        });
+        assert_eq!(squares, items.iter().map(|n| n * n).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_map_thread_counts() {
+        let items = [1, 2, 3];
+        assert_eq!(map(&items, 1, |n| n + 1), vec![2, 3, 4]);
+        assert_eq!(map(&items, 64, |n| n + 1), vec![2, 3, 4]);
+        assert_eq!(map(&[] as &[u8], 4, |n| *n), Vec::<u8>::new());
+    }
+}
@@ -0,0 +1,288 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+const CARGO_TOML: &str = r#"[package]
+name = "{crate}"
+version = "0.1.0"
+edition = "2024"
+
+[dependencies]
+aoc-core = { path = "../../crates/aoc-core" }
+aoc-parse = { path = "../../crates/aoc-parse" }
+nom = "8.0.0"
+
+[features]
+embed-input = []
+"#;
+
+const MAIN_RS: &str = r#"fn main() {
+    aoc_core::main::<{crate}::{type}>();
+}
+"#;
+
+const LIB_RS: &str = r#"use aoc_core::{
+    Error, Result, Solution,
+    diagnostic::{IResult, parse_all},
+};
+use aoc_parse::lines;
+use nom::{Parser, character::complete::not_line_ending, error::context};
+
+/// Parses the input as plain lines. Replace with the puzzle's own grammar.
+pub fn parsed_input(input: &str) -> IResult<&str, Vec<String>> {
+    lines(context(
+        "expected a line",
+        not_line_ending.map(String::from),
+    ))
+    .parse(input)
+}
+
+#[cfg(feature = "embed-input")]
+const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../../../inputs/{crate}.txt"));
+#[cfg(not(feature = "embed-input"))]
+const EMBEDDED_INPUT: Option<&str> = None;
+
+pub struct {type};
+
+impl Solution for {type} {
+    const DAY: u8 = {day};
+    const EMBEDDED_INPUT: Option<&'static str> = EMBEDDED_INPUT;
+
+    type Model = Vec<String>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        parse_all(input, parsed_input)
+    }
+
+    fn part1(_lines: &Self::Model) -> Result<u64> {
+        Err(Error::solve("part 1 is not solved yet"))
+    }
+
+    fn part2(_lines: &Self::Model) -> Result<u64> {
+        Err(Error::solve("part 2 is not solved yet"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_core::solution_tests!({type});
+
+    const EXAMPLE_INPUT: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn test_parse() {
+        assert!({type}::parse(EXAMPLE_INPUT).is_ok());
+    }
+}
+"#;
+
+const EXAMPLE_ANSWERS: &str = "# <part> <answer> for example.txt, as given in the puzzle text\n";
+
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{crate}", &format!("day{:02}", day))
+        .replace("{type}", &format!("Day{:02}", day))
+        .replace("{day}", &day.to_string())
+}
+
+/// Finds the workspace root: the nearest ancestor of the current directory
+/// whose `Cargo.toml` declares a `[workspace]`.
+pub fn workspace_root() -> io::Result<PathBuf> {
+    let cwd = env::current_dir()?;
+    cwd.ancestors()
+        .find(|dir| {
+            fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.contains("[workspace]"))
+        })
+        .map(Path::to_path_buf)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                "not inside the workspace: no Cargo.toml with [workspace] above the current directory",
+            )
+        })
+}
+
+/// Inserts `line` among the registry lines matched by `is_entry`, keeping
+/// them sorted. Fails if the line is already there or if there is no registry
+/// to insert into.
+fn insert_entry(text: &str, line: &str, is_entry: impl Fn(&str) -> bool) -> Result<String, String> {
+    let mut lines = text.lines().collect::<Vec<_>>();
+    let entries = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| is_entry(l))
+        .map(|(idx, _)| idx)
+        .collect::<Vec<_>>();
+
+    if entries.iter().any(|&idx| lines[idx] == line) {
+        return Err(format!("`{}` is already registered", line.trim()));
+    }
+
+    let idx = match entries.iter().find(|&&idx| lines[idx] > line) {
+        Some(&idx) => idx,
+        None => match entries.last() {
+            Some(&last) => last + 1,
+            None => return Err(format!("no place to register `{}`", line.trim())),
+        },
+    };
+    lines.insert(idx, line);
+
+    let mut out = lines.join("\n");
+    if text.ends_with('\n') {
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+/// Adds the day as a dependency of the runner and to its `embed-input`
+/// feature.
+pub fn register_manifest(manifest: &str, day: u8) -> Result<String, String> {
+    let manifest = insert_entry(
+        manifest,
+        &render(r#"{crate} = { path = "../../days/{crate}" }"#, day),
+        |line| line.starts_with("day") && line.contains("path = "),
+    )?;
+    insert_entry(
+        &manifest,
+        &render(r#"    "{crate}/embed-input","#, day),
+        |line| line.trim_start().starts_with("\"day") && line.ends_with("/embed-input\","),
+    )
+}
+
+/// Adds the day to the runner's list of days.
+pub fn register_days(days: &str, day: u8) -> Result<String, String> {
+    insert_entry(
+        days,
+        &render("    &Solver::<{crate}::{type}>::new(),", day),
+        |line| line.trim_start().starts_with("&Solver::<day"),
+    )
+}
+
+fn write_new(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    }
+    fs::write(path, contents).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn update(path: &Path, edit: impl FnOnce(&str) -> Result<String, String>) -> Result<(), String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let text = edit(&text).map_err(|e| format!("{}: {}", path.display(), e))?;
+    fs::write(path, text).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Creates `days/dayNN` under `root` and registers it with the runner,
+/// returning the files created or changed.
+pub fn create(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    let crate_dir = root.join("days").join(format!("day{:02}", day));
+    if crate_dir.exists() {
+        return Err(format!("{} already exists", crate_dir.display()));
+    }
+
+    let runner_manifest = root.join("crates/aoc/Cargo.toml");
+    let runner_days = root.join("crates/aoc/src/days.rs");
+
+    // Edit the registry in memory first, so a failure leaves nothing behind
+    let read =
+        |path: &Path| fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e));
+    register_manifest(&read(&runner_manifest)?, day)?;
+    register_days(&read(&runner_days)?, day)?;
+
+    let files = [
+        (crate_dir.join("Cargo.toml"), render(CARGO_TOML, day)),
+        (crate_dir.join("src/main.rs"), render(MAIN_RS, day)),
+        (crate_dir.join("src/lib.rs"), render(LIB_RS, day)),
+        (crate_dir.join("fixtures/example.txt"), String::new()),
+        (
+            crate_dir.join("fixtures/example.answers"),
+            EXAMPLE_ANSWERS.to_string(),
+        ),
+    ];
+
+    let mut touched = Vec::new();
+    for (path, contents) in files {
+        write_new(&path, &contents)?;
+        touched.push(path);
+    }
+
+    update(&runner_manifest, |text| register_manifest(text, day))?;
+    update(&runner_days, |text| register_days(text, day))?;
+    touched.extend([runner_manifest, runner_days]);
+
+    Ok(touched)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"[dependencies]
+aoc-core = { path = "../aoc-core" }
+day01 = { path = "../../days/day01" }
+day03 = { path = "../../days/day03" }
+
+[features]
+embed-input = [
+    "day01/embed-input",
+    "day03/embed-input",
+]
+"#;
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            render(MAIN_RS, 8),
+            "fn main() {\n    aoc_core::main::<day08::Day08>();\n}\n"
+        );
+        assert!(render(LIB_RS, 12).contains("const DAY: u8 = 12;"));
+        assert!(render(LIB_RS, 12).contains("use aoc_parse::lines;"));
+        assert!(
+            render(CARGO_TOML, 12).contains("aoc-parse = { path = \"../../crates/aoc-parse\" }")
+        );
+    }
+
+    #[test]
+    fn test_register_manifest() {
+        assert_eq!(
+            register_manifest(MANIFEST, 2).unwrap(),
+            r#"[dependencies]
+aoc-core = { path = "../aoc-core" }
+day01 = { path = "../../days/day01" }
+day02 = { path = "../../days/day02" }
+day03 = { path = "../../days/day03" }
+
+[features]
+embed-input = [
+    "day01/embed-input",
+    "day02/embed-input",
+    "day03/embed-input",
+]
+"#
+        );
+
+        let manifest = register_manifest(MANIFEST, 9).unwrap();
+        assert!(manifest.contains("day03 = { path = \"../../days/day03\" }\nday09"));
+        assert!(manifest.contains("\"day03/embed-input\",\n    \"day09/embed-input\",\n]"));
+
+        assert_eq!(
+            register_manifest(MANIFEST, 3),
+            Err("`day03 = { path = \"../../days/day03\" }` is already registered".to_string())
+        );
+    }
+
+    #[test]
+    fn test_register_days() {
+        let days = "pub static ALL: &[&dyn Day] = &[\n    &Solver::<day07::Day07>::new(),\n];\n";
+        assert_eq!(
+            register_days(days, 4).unwrap(),
+            "pub static ALL: &[&dyn Day] = &[\n    &Solver::<day04::Day04>::new(),\n    &Solver::<day07::Day07>::new(),\n];\n"
+        );
+        assert!(register_days("", 4).is_err());
+    }
+}
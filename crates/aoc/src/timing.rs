@@ -0,0 +1,189 @@
+use std::{fmt::Write, time::Duration};
+
+use aoc_core::{Part, Run};
+
+/// Min, median and max of repeated measurements of one stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        samples.sort();
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+
+        Some(Stats {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayTiming {
+    pub day: u8,
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+    pub total: Stats,
+}
+
+impl DayTiming {
+    /// Summarizes repeated runs of the same day. `runs` must not be empty.
+    pub fn from_runs(runs: &[Run]) -> Self {
+        let part = |part: Part| {
+            Stats::from_samples(
+                runs.iter()
+                    .filter_map(|run| run.answer(part))
+                    .map(|answer| answer.elapsed)
+                    .collect(),
+            )
+        };
+
+        DayTiming {
+            day: runs[0].day,
+            runs: runs.len(),
+            parse: Stats::from_samples(runs.iter().map(|run| run.parse).collect())
+                .expect("there should be at least one run"),
+            part1: part(Part::One),
+            part2: part(Part::Two),
+            total: Stats::from_samples(runs.iter().map(Run::total).collect())
+                .expect("there should be at least one run"),
+        }
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    match nanos {
+        0..1_000 => format!("{}ns", nanos),
+        1_000..1_000_000 => format!("{:.1}µs", nanos as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.1}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", duration.as_secs_f64()),
+    }
+}
+
+fn row(out: &mut String, day: &str, stage: &str, stats: Option<&Stats>) {
+    let [min, median, max] = match stats {
+        Some(stats) => [stats.min, stats.median, stats.max].map(format_duration),
+        None => ["-", "-", "-"].map(String::from),
+    };
+    let _ = writeln!(
+        out,
+        "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}",
+        day, stage, min, median, max
+    );
+}
+
+/// Renders a table with the parse, part and total timings of every day, and
+/// a final row summing the medians across days.
+pub fn table(timings: &[DayTiming]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}",
+        "Day", "Stage", "Min", "Median", "Max"
+    );
+
+    for timing in timings {
+        let day = format!("{:02}", timing.day);
+        row(&mut out, &day, "parse", Some(&timing.parse));
+        row(&mut out, "", "part 1", timing.part1.as_ref());
+        row(&mut out, "", "part 2", timing.part2.as_ref());
+        row(&mut out, "", "total", Some(&timing.total));
+    }
+
+    let total = timings.iter().map(|timing| timing.total.median).sum();
+    let _ = write!(
+        out,
+        "{:>3}  {:<6}  {:>10}  {:>10}",
+        "All",
+        "total",
+        "",
+        format_duration(total)
+    );
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{Answer, PartAnswer};
+
+    use super::*;
+
+    fn run(parse: u64, part1: u64) -> Run {
+        Run {
+            day: 3,
+            parse: Duration::from_micros(parse),
+            answers: vec![PartAnswer {
+                day: 3,
+                part: Part::One,
+                answer: Answer::Number(357),
+                answer_type: "u64",
+                elapsed: Duration::from_micros(part1),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        assert_eq!(Stats::from_samples(vec![]), None);
+
+        let ms = Duration::from_millis;
+        assert_eq!(
+            Stats::from_samples(vec![ms(5), ms(1), ms(3)]),
+            Some(Stats {
+                min: ms(1),
+                median: ms(3),
+                max: ms(5),
+            })
+        );
+        assert_eq!(
+            Stats::from_samples(vec![ms(4), ms(1), ms(2), ms(8)]).map(|s| s.median),
+            Some(ms(3))
+        );
+    }
+
+    #[test]
+    fn test_day_timing() {
+        let timing = DayTiming::from_runs(&[run(10, 1), run(30, 3), run(20, 2)]);
+        assert_eq!(timing.day, 3);
+        assert_eq!(timing.runs, 3);
+        assert_eq!(timing.parse.median, Duration::from_micros(20));
+        assert_eq!(timing.part1.map(|s| s.max), Some(Duration::from_micros(3)));
+        assert_eq!(timing.part2, None);
+        assert_eq!(timing.total.min, Duration::from_micros(11));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_nanos(1_260)), "1.3µs");
+        assert_eq!(format_duration(Duration::from_micros(2_500)), "2.5ms");
+        assert_eq!(format_duration(Duration::from_millis(1_500)), "1.50s");
+    }
+
+    #[test]
+    fn test_table() {
+        let table = table(&[DayTiming::from_runs(&[run(10, 1)])]);
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 6);
+        assert_eq!(lines[1], " 03  parse       10.0µs      10.0µs      10.0µs");
+        assert_eq!(lines[3], "     part 2           -           -           -");
+        assert_eq!(lines[5], "All  total                   11.0µs");
+    }
+}
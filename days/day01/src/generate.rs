@@ -0,0 +1,35 @@
+use aoc_core::Rng;
+
+/// Longest rotation generated, in clicks. Long enough to wrap the dial
+/// several times.
+const MAX_DISTANCE: u64 = 999;
+
+/// `size` rotations, one per line.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let dir = if rng.chance(1, 2) { 'L' } else { 'R' };
+            format!("{}{}\n", dir, rng.range(1..=MAX_DISTANCE))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{Day, Part, Solver};
+
+    use super::*;
+    use crate::Day01;
+
+    #[test]
+    fn test_input() {
+        let input = input(&mut Rng::new(1), 50);
+        assert_eq!(input.lines().count(), 50);
+        assert_eq!(input, super::input(&mut Rng::new(1), 50));
+
+        for seed in 0..20 {
+            let input = super::input(&mut Rng::new(seed), 200);
+            Solver::<Day01>::new().solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
@@ -0,0 +1,320 @@
+use std::{
+    fmt::{self, Display},
+    io::BufRead,
+};
+
+use aoc_core::{
+    Result, Rng, Solution, Trace,
+    diagnostic::{IResult, parse_all},
+    stream,
+};
+use aoc_parse::{lines, prefixed, signed};
+use nom::{Parser, character::complete::multispace0, error::context, sequence::preceded};
+
+pub mod generate;
+pub mod reference;
+
+/// One rotation of the dial, by a number of clicks.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Direction {
+    Left(i64),
+    Right(i64),
+}
+
+/// Writes the rotation as it appears in the input, e.g. `L68`.
+impl Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Direction::Left(steps) => write!(f, "L{}", steps),
+            Direction::Right(steps) => write!(f, "R{}", steps),
+        }
+    }
+}
+
+/// Parses a rotation such as `L68`.
+pub fn direction(input: &str) -> IResult<&str, Direction> {
+    prefixed("LR", context("expected a rotation distance", signed))
+        .map(|(dir, value)| match dir {
+            'L' => Direction::Left(value),
+            'R' => Direction::Right(value),
+            _ => unreachable!(),
+        })
+        .parse(input)
+}
+
+/// Parses one rotation per line.
+pub fn directions(input: &str) -> IResult<&str, Vec<Direction>> {
+    lines(direction).parse(input)
+}
+
+/// A dial with positions `0..size` in a circle, currently pointing at one of
+/// them. Turning right counts up, turning left counts down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dial {
+    size: i64,
+    position: i64,
+}
+
+impl Dial {
+    /// A dial of `size` positions starting at `start`, or `None` if there are
+    /// no positions or `start` is not one of them.
+    pub fn new(size: i64, start: i64) -> Option<Self> {
+        (size > 0 && (0..size).contains(&start)).then_some(Dial {
+            size,
+            position: start,
+        })
+    }
+
+    pub fn size(&self) -> i64 {
+        self.size
+    }
+
+    pub fn position(&self) -> i64 {
+        self.position
+    }
+
+    /// Turns the dial, returning the new position and how many times the dial
+    /// passed or landed on zero on the way.
+    pub fn turn(&mut self, dir: &Direction) -> (i64, i64) {
+        let (new_position, zeros_crossed) = match dir {
+            Direction::Left(steps) => {
+                let new_position = self.position - steps;
+                let zeros_crossed = match self.position {
+                    _ if new_position > 0 => 0,
+                    0 => new_position / -self.size,
+                    _ => (new_position / -self.size) + 1,
+                };
+                (new_position, zeros_crossed)
+            }
+            Direction::Right(steps) => {
+                let new_position = self.position + steps;
+                (new_position, new_position / self.size)
+            }
+        };
+
+        self.position = new_position.rem_euclid(self.size);
+        (self.position, zeros_crossed)
+    }
+}
+
+/// The puzzle's dial: 100 positions, starting at 50.
+impl Default for Dial {
+    fn default() -> Self {
+        Dial {
+            size: 100,
+            position: 50,
+        }
+    }
+}
+
+/// Turns `dial` through `dirs`, recording for every rotation the position
+/// before and after it, whether it landed on zero (1 or 0) and how many times
+/// it passed or landed on zero. The `landed` column sums to the part 1 answer
+/// and the `zeros` column to the part 2 answer.
+pub fn trace_turns(mut dial: Dial, dirs: &[Direction]) -> Trace {
+    let mut trace = Trace::new(&["step", "rotation", "before", "after", "landed", "zeros"]);
+    for (idx, dir) in dirs.iter().enumerate() {
+        let before = dial.position();
+        let (after, zeros) = dial.turn(dir);
+        trace.push(vec![
+            (idx + 1).into(),
+            dir.to_string().into(),
+            before.into(),
+            after.into(),
+            u8::from(after == 0).into(),
+            zeros.into(),
+        ]);
+    }
+    trace
+}
+
+/// Streaming counterpart of [`Day01`]: reads one rotation per line from
+/// `reader` and returns both answers, how often the dial lands on zero and
+/// how often it passes zero, without keeping the rotations in memory. Blank
+/// lines are skipped.
+pub fn solve_stream(reader: impl BufRead) -> Result<(i64, i64)> {
+    let mut dial = Dial::default();
+    let (mut landed, mut crossed) = (0, 0);
+
+    stream::for_each_line(reader, |line_number, line| {
+        if line.is_empty() {
+            return Ok(());
+        }
+
+        let dir = stream::parse_line(line_number, line, direction)?;
+        let (position, zeros_crossed) = dial.turn(&dir);
+        landed += i64::from(position == 0);
+        crossed += zeros_crossed;
+        Ok(())
+    })?;
+
+    Ok((landed, crossed))
+}
+
+#[cfg(feature = "embed-input")]
+const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../../../inputs/day01.txt"));
+#[cfg(not(feature = "embed-input"))]
+const EMBEDDED_INPUT: Option<&str> = None;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const EMBEDDED_INPUT: Option<&'static str> = EMBEDDED_INPUT;
+
+    type Model = Vec<Direction>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        parse_all(input, preceded(multispace0, directions))
+    }
+
+    fn part1(dirs: &Self::Model) -> Result<i64> {
+        let mut dial = Dial::default();
+        let counted_zeros = dirs.iter().filter(|dir| dial.turn(dir).0 == 0).count();
+
+        Ok(counted_zeros as i64)
+    }
+
+    fn part2(dirs: &Self::Model) -> Result<i64> {
+        let mut dial = Dial::default();
+        let counted_zeros = dirs.iter().map(|dir| dial.turn(dir).1).sum();
+
+        Ok(counted_zeros)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    fn trace(dirs: &Self::Model) -> Option<Trace> {
+        Some(trace_turns(Dial::default(), dirs))
+    }
+
+    fn reference_part1(input: &str) -> Option<i64> {
+        Some(reference::part1(input))
+    }
+
+    fn reference_part2(input: &str) -> Option<i64> {
+        Some(reference::part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_core::solution_tests!(Day01);
+
+    const EXAMPLE_INPUT: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn test_directions() {
+        let (_, dirs) = directions(EXAMPLE_INPUT).unwrap();
+        assert_eq!(dirs.len(), 10);
+        assert_eq!(dirs[0], Direction::Left(68));
+        assert_eq!(dirs[1], Direction::Left(30));
+        assert_eq!(dirs[2], Direction::Right(48));
+    }
+
+    #[test]
+    fn test_trailing_input() {
+        let err = Day01::parse("L68\nL30\nX48\nL5\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 3, column 1: expected 'L' or 'R'"
+        );
+    }
+
+    #[test]
+    fn test_solve_stream() {
+        assert_eq!(solve_stream(EXAMPLE_INPUT.as_bytes()).unwrap(), (3, 6));
+
+        let err = solve_stream("L68\r\nL30\r\nR4x\r\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 3, column 3: unexpected \"x\", expected end of input"
+        );
+    }
+
+    #[test]
+    fn test_turn() {
+        let mut dial = Dial::default();
+        let (pos, zeros) = dial.turn(&Direction::Left(68));
+        assert_eq!(pos, 82);
+        assert_eq!(zeros, 1);
+
+        let (pos, zeros) = dial.turn(&Direction::Left(30));
+        assert_eq!(pos, 52);
+        assert_eq!(zeros, 0);
+
+        let (pos, zeros) = dial.turn(&Direction::Right(48));
+        assert_eq!(pos, 0);
+        assert_eq!(zeros, 1);
+
+        let (pos, zeros) = dial.turn(&Direction::Left(5));
+        assert_eq!(pos, 95);
+        assert_eq!(zeros, 0);
+
+        let (pos, zeros) = dial.turn(&Direction::Right(60));
+        assert_eq!(pos, 55);
+        assert_eq!(zeros, 1);
+
+        let (pos, zeros) = dial.turn(&Direction::Left(55));
+        assert_eq!(pos, 0);
+        assert_eq!(zeros, 1);
+
+        let (pos, zeros) = dial.turn(&Direction::Left(1));
+        assert_eq!(pos, 99);
+        assert_eq!(zeros, 0);
+
+        let (pos, zeros) = dial.turn(&Direction::Left(99));
+        assert_eq!(pos, 0);
+        assert_eq!(zeros, 1);
+
+        let (pos, zeros) = dial.turn(&Direction::Right(14));
+        assert_eq!(pos, 14);
+        assert_eq!(zeros, 0);
+
+        let (pos, zeros) = dial.turn(&Direction::Left(82));
+        assert_eq!(pos, 32);
+        assert_eq!(zeros, 1);
+    }
+
+    #[test]
+    fn test_dial_sizes() {
+        assert_eq!(Dial::new(0, 0), None);
+        assert_eq!(Dial::new(10, 10), None);
+        assert_eq!(Dial::new(10, -1), None);
+
+        let mut dial = Dial::new(10, 0).unwrap();
+        assert_eq!(dial.turn(&Direction::Right(25)), (5, 2));
+        assert_eq!(dial.turn(&Direction::Left(5)), (0, 1));
+        assert_eq!(dial.turn(&Direction::Left(20)), (0, 2));
+        assert_eq!(dial.turn(&Direction::Left(3)), (7, 0));
+        assert_eq!(dial.size(), 10);
+        assert_eq!(dial.position(), 7);
+    }
+
+    #[test]
+    fn test_trace_turns() {
+        let dirs = Day01::parse(EXAMPLE_INPUT).unwrap();
+        let trace = trace_turns(Dial::default(), &dirs);
+        assert_eq!(trace.rows().len(), 10);
+        assert_eq!(
+            trace.to_csv().lines().take(3).collect::<Vec<_>>(),
+            vec![
+                "step,rotation,before,after,landed,zeros",
+                "1,L68,50,82,0,1",
+                "2,L30,82,52,0,0",
+            ]
+        );
+
+        let landed = trace.rows().iter().filter(|row| row[4] == "1").count();
+        assert_eq!(landed as i64, Day01::part1(&dirs).unwrap());
+    }
+
+    #[test]
+    fn test_input() {}
+}
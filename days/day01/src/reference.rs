@@ -0,0 +1,54 @@
+//! Click-by-click dial simulation, to cross-check the arithmetic in
+//! [`crate::Dial`].
+
+/// Turns the dial one click at a time from 50 and calls `on_click` with the
+/// position after every click, and `on_rotation` after every rotation.
+fn simulate(input: &str, mut on_click: impl FnMut(i64), mut on_rotation: impl FnMut(i64)) {
+    let mut position: i64 = 50;
+
+    for line in input.lines().filter(|line| !line.is_empty()) {
+        let (dir, clicks) = line.split_at(1);
+        let delta = if dir == "L" { -1 } else { 1 };
+
+        for _ in 0..clicks.parse::<i64>().expect("distance should be a number") {
+            position = (position + delta).rem_euclid(100);
+            on_click(position);
+        }
+        on_rotation(position);
+    }
+}
+
+/// Rotations that leave the dial at zero.
+pub fn part1(input: &str) -> i64 {
+    let mut zeros = 0;
+    simulate(input, |_| {}, |position| zeros += i64::from(position == 0));
+    zeros
+}
+
+/// Clicks that land on zero, during or at the end of a rotation.
+pub fn part2(input: &str) -> i64 {
+    let mut zeros = 0;
+    simulate(input, |position| zeros += i64::from(position == 0), |_| {});
+    zeros
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::differential;
+
+    use super::*;
+    use crate::{Day01, generate};
+
+    const EXAMPLE_INPUT: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_eq!(part1(EXAMPLE_INPUT), 3);
+        assert_eq!(part2(EXAMPLE_INPUT), 6);
+    }
+
+    #[test]
+    fn test_differential() {
+        differential::assert_agree::<Day01>(200, |rng| generate::input(rng, 50));
+    }
+}
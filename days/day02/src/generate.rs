@@ -0,0 +1,44 @@
+use aoc_core::Rng;
+
+/// Most IDs in one generated range. Solvers visit every ID, so this bounds
+/// how long a generated input takes to solve.
+const MAX_SPAN: u64 = 10_000;
+
+/// `size` comma separated ID ranges on one line, with IDs of 1 to 10 digits.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    input_with(rng, size, MAX_SPAN)
+}
+
+/// Like [`input`], with ranges of at most `max_span` IDs past their start.
+pub fn input_with(rng: &mut Rng, size: usize, max_span: u64) -> String {
+    let ranges = (0..size)
+        .map(|_| {
+            let digits = rng.range(1..=10) as u32;
+            let start = rng.range(10u64.pow(digits - 1)..=10u64.pow(digits) - 1);
+            let end = start + rng.range(0..=max_span);
+            format!("{}-{}", start, end)
+        })
+        .collect::<Vec<_>>();
+
+    format!("{}\n", ranges.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{Day, Part, Solver};
+
+    use super::*;
+    use crate::Day02;
+
+    #[test]
+    fn test_input() {
+        let input = input(&mut Rng::new(1), 30);
+        assert_eq!(input.split(',').count(), 30);
+        assert_eq!(input, super::input(&mut Rng::new(1), 30));
+
+        for seed in 0..3 {
+            let input = super::input(&mut Rng::new(seed), 5);
+            Solver::<Day02>::new().solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use std::ops::RangeInclusive;
+
+use aoc_core::{
+    Result, Rng, Solution,
+    diagnostic::{IResult, parse_all},
+};
+use aoc_parse::{comma_list, inclusive_range};
+use aoc_ranges::RangeSet;
+use nom::{Parser, character, sequence::preceded};
+
+pub mod generate;
+pub mod reference;
+
+/// Parses an inclusive `start-end` range of IDs.
+pub fn id_range(input: &str) -> IResult<&str, RangeInclusive<u64>> {
+    inclusive_range(input)
+}
+
+/// Parses a comma separated list of ID ranges.
+pub fn id_ranges(input: &str) -> IResult<&str, Vec<RangeInclusive<u64>>> {
+    comma_list(id_range).parse(input)
+}
+
+/// Whether `id_str` is made of `factor` copies of the same chunk of digits.
+pub fn is_repeated(id_str: &str, factor: usize) -> bool {
+    if id_str.len() < factor {
+        return false;
+    }
+
+    let len = id_str.len();
+    let chunk_size = if len.is_multiple_of(factor) {
+        len / factor
+    } else {
+        len / factor + 1
+    };
+
+    let chars = id_str.chars().collect::<Vec<char>>();
+    let chunks = chars.chunks(chunk_size).collect::<Vec<&[char]>>();
+
+    if let Some((first, rest)) = chunks.split_first() {
+        rest.iter().all(|chunk| chunk == first)
+    } else {
+        false
+    }
+}
+
+/// The ID ranges as listed, and the set of IDs they cover. Ranges may
+/// overlap, and an ID counts once for every range that lists it.
+#[derive(Debug, PartialEq, Eq)]
+pub struct IdRanges {
+    ranges: Vec<RangeInclusive<u64>>,
+    ids: RangeSet,
+}
+
+impl IdRanges {
+    pub fn new(ranges: Vec<RangeInclusive<u64>>) -> Self {
+        Self {
+            ids: ranges.iter().cloned().collect(),
+            ranges,
+        }
+    }
+
+    pub fn ranges(&self) -> &[RangeInclusive<u64>] {
+        &self.ranges
+    }
+
+    pub fn ids(&self) -> &RangeSet {
+        &self.ids
+    }
+
+    /// Sum of the IDs matching `matches`, each once per range listing it.
+    /// Every distinct ID is checked once, however much the ranges overlap.
+    pub fn sum_matching(&self, matches: impl Fn(u64) -> bool) -> u64 {
+        self.ids
+            .values()
+            .filter(|&id| matches(id))
+            .map(|id| id * self.ranges.iter().filter(|r| r.contains(&id)).count() as u64)
+            .sum()
+    }
+}
+
+#[cfg(feature = "embed-input")]
+const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../../../inputs/day02.txt"));
+#[cfg(not(feature = "embed-input"))]
+const EMBEDDED_INPUT: Option<&str> = None;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const EMBEDDED_INPUT: Option<&'static str> = EMBEDDED_INPUT;
+
+    type Model = IdRanges;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        let ranges = parse_all(input, preceded(character::complete::multispace0, id_ranges))?;
+        Ok(IdRanges::new(ranges))
+    }
+
+    fn part1(ranges: &Self::Model) -> Result<u64> {
+        Ok(ranges.sum_matching(|v| is_repeated(&v.to_string(), 2)))
+    }
+
+    fn part2(ranges: &Self::Model) -> Result<u64> {
+        Ok(ranges.sum_matching(|v| {
+            let id_str = v.to_string();
+            (2..=id_str.len()).any(|factor| is_repeated(&id_str, factor))
+        }))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    fn reference_part1(input: &str) -> Option<u64> {
+        Some(reference::part1(input))
+    }
+
+    fn reference_part2(input: &str) -> Option<u64> {
+        Some(reference::part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_core::solution_tests!(Day02);
+
+    #[test]
+    fn test_id_range() {
+        let input = "100-200";
+        let (_, range) = id_range(input).unwrap();
+        assert_eq!(range, 100..=200);
+    }
+
+    #[test]
+    fn test_id_ranges() {
+        let input = "100-200,300-400";
+        let (_, ranges) = id_ranges(input).unwrap();
+        assert_eq!(ranges, vec![100..=200, 300..=400]);
+    }
+
+    #[test]
+    fn test_trailing_input() {
+        let err = Day02::parse("11-22,95-115;998-1012\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 1, column 13: unexpected \";998-1012\", expected end of input"
+        );
+    }
+
+    #[test]
+    fn test_reversed_range() {
+        let err = Day02::parse("11-22,95-15").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid value at line 1, column 7: range 95-15 ends before it starts"
+        );
+    }
+
+    #[test]
+    fn test_is_repeated() {
+        assert!(is_repeated("1212", 2));
+        assert!(!is_repeated("1234", 2));
+        assert!(is_repeated("1188511885", 2));
+        assert!(!is_repeated("1188511882", 2));
+        assert!(!is_repeated("111", 2));
+        assert!(!is_repeated("999", 2));
+        assert!(is_repeated("111", 3));
+        assert!(is_repeated("999", 3));
+    }
+
+    #[test]
+    fn test_overlapping_ranges() {
+        // An ID in two ranges counts once for each
+        let ranges = Day02::parse("11-22,15-33").unwrap();
+        assert_eq!(ranges.ranges(), [11..=22, 15..=33]);
+        assert_eq!(ranges.ids().iter().collect::<Vec<_>>(), vec![11..=33]);
+        assert_eq!(Day02::part1(&ranges).unwrap(), 11 + 22 + 22 + 33);
+    }
+}
@@ -0,0 +1,52 @@
+//! Per-ID check by building the repeated string, to cross-check the chunk
+//! comparison in [`crate::is_repeated`].
+
+fn ids(input: &str) -> impl Iterator<Item = u64> + '_ {
+    input.trim().split(',').flat_map(|range| {
+        let (start, end) = range.split_once('-').expect("range should have a '-'");
+        let start = start.parse::<u64>().expect("start should be a number");
+        let end = end.parse::<u64>().expect("end should be a number");
+        start..=end
+    })
+}
+
+/// Whether `id` is some prefix written out `times` times.
+fn repeats(id: &str, times: usize) -> bool {
+    id.len().is_multiple_of(times) && id[..id.len() / times].repeat(times) == id
+}
+
+/// Sum of IDs made of a digit sequence written twice.
+pub fn part1(input: &str) -> u64 {
+    ids(input).filter(|id| repeats(&id.to_string(), 2)).sum()
+}
+
+/// Sum of IDs made of a digit sequence written at least twice.
+pub fn part2(input: &str) -> u64 {
+    ids(input)
+        .filter(|id| {
+            let id = id.to_string();
+            (2..=id.len()).any(|times| repeats(&id, times))
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::differential;
+
+    use super::*;
+    use crate::{Day02, generate};
+
+    const EXAMPLE_INPUT: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_eq!(part1(EXAMPLE_INPUT), 1227775554);
+        assert_eq!(part2(EXAMPLE_INPUT), 4174379265);
+    }
+
+    #[test]
+    fn test_differential() {
+        differential::assert_agree::<Day02>(100, |rng| generate::input_with(rng, 10, 100));
+    }
+}
@@ -0,0 +1,43 @@
+use aoc_core::Rng;
+
+/// Batteries per bank, as in the real input.
+const BANK_LEN: usize = 100;
+
+/// `size` banks of single digit joltages 1-9, one per line.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    input_with(rng, size, BANK_LEN)
+}
+
+/// Like [`input`], with `bank_len` batteries per bank.
+pub fn input_with(rng: &mut Rng, size: usize, bank_len: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let mut bank = (0..bank_len)
+                .map(|_| char::from(b'0' + rng.range(1..=9) as u8))
+                .collect::<String>();
+            bank.push('\n');
+            bank
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{Day, Part, Solver};
+
+    use super::*;
+    use crate::Day03;
+
+    #[test]
+    fn test_input() {
+        let input = input(&mut Rng::new(1), 40);
+        assert_eq!(input.lines().count(), 40);
+        assert!(input.lines().all(|bank| bank.len() == BANK_LEN));
+        assert_eq!(input, super::input(&mut Rng::new(1), 40));
+
+        for seed in 0..20 {
+            let input = super::input(&mut Rng::new(seed), 100);
+            Solver::<Day03>::new().solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
@@ -0,0 +1,249 @@
+use std::io::BufRead;
+
+use aoc_core::{
+    Error, Result, Rng, Solution,
+    diagnostic::{IResult, parse_all},
+    stream,
+};
+use aoc_parse::{digit, lines};
+use nom::{Parser, multi::many1};
+
+pub mod generate;
+pub mod reference;
+
+pub fn battery(input: &str) -> IResult<&str, u64> {
+    digit.map(u64::from).parse(input)
+}
+
+/// Parses a bank of single digit battery joltages.
+pub fn battery_bank(input: &str) -> IResult<&str, Vec<u64>> {
+    many1(battery).parse(input)
+}
+
+/// Parses one bank per line.
+pub fn battery_banks(input: &str) -> IResult<&str, Vec<Vec<u64>>> {
+    lines(battery_bank).parse(input)
+}
+
+fn digits_to_number(digits: &[u64]) -> u64 {
+    digits.iter().fold(0, |acc, digit| acc * 10 + digit)
+}
+
+/// Largest number formed by turning on `num_digits` batteries of `bank`,
+/// keeping their order. The bank must have at least `num_digits` batteries.
+pub fn largest_joltage(bank: &[u64], num_digits: usize) -> u64 {
+    let bank_size = bank.len();
+    let (_, digits) = (0..num_digits).fold(
+        (0, Vec::with_capacity(num_digits)),
+        |(start_index, mut acc), idx| {
+            let (found_index, value) = bank[start_index..(bank_size - num_digits + idx + 1)]
+                .iter()
+                .enumerate()
+                .rev()
+                .max_by(|(_, a), (_, b)| a.cmp(b))
+                .expect("there should be at least 1 item");
+
+            acc.push(*value);
+
+            (start_index + found_index + 1, acc)
+        },
+    );
+
+    // let digits = bank.iter().fold(Vec::with_capacity(num_digits), |mut acc: Vec<u64>, battery| {
+    //     if acc.len() >= num_digits {
+    //         // Find the index of the largest digit in acc
+    //         let (max_index, _) = acc.iter().enumerate().rev().max_by(|a, b| a.1.cmp(b.1)).expect("there is at least one element");
+
+    //         let search_index = if max_index == 0 { num_digits } else { max_index };
+
+    //         // Find the smallest digit in acc to the left of the max value, unless the max is at index 0
+    //         let (min_index, _) = acc.iter().take(search_index).enumerate().min_by(|a, b| a.1.cmp(b.1)).expect("there is at least one element");
+
+    //         let current_value = digits_to_number(&acc);
+    //         let potential_value = digits_to_number(&{
+    //             let mut temp = acc.clone();
+    //             temp.remove(min_index);
+    //             temp.push(*battery);
+    //             temp
+    //         });
+
+    //         if current_value >= potential_value {
+    //             return acc;
+    //         }
+
+    //         acc.remove(min_index);
+    //     }
+
+    //     acc.push(*battery);
+
+    //     return acc;
+    // });
+
+    digits_to_number(&digits)
+}
+
+/// Sum of the largest joltage of every bank, failing on a bank that is too
+/// short.
+pub fn total_joltage(banks: &[Vec<u64>], num_digits: usize) -> Result<u64> {
+    banks
+        .iter()
+        .enumerate()
+        .map(|(idx, bank)| bank_joltage(idx + 1, bank, num_digits))
+        .sum()
+}
+
+/// [`largest_joltage`] of the `bank_number`th bank, failing if it is too
+/// short.
+fn bank_joltage(bank_number: usize, bank: &[u64], num_digits: usize) -> Result<u64> {
+    if bank.len() < num_digits {
+        return Err(Error::solve(format!(
+            "bank {} has {} batteries, need at least {}",
+            bank_number,
+            bank.len(),
+            num_digits
+        )));
+    }
+    Ok(largest_joltage(bank, num_digits))
+}
+
+/// Streaming counterpart of [`Day03`]: reads one bank per line from `reader`
+/// and returns both answers, keeping only the current bank in memory. Blank
+/// lines are skipped.
+pub fn solve_stream(reader: impl BufRead) -> Result<(u64, u64)> {
+    let (mut part1, mut part2) = (0, 0);
+    let mut bank_number = 0;
+
+    stream::for_each_line(reader, |line_number, line| {
+        if line.is_empty() {
+            return Ok(());
+        }
+
+        let bank = stream::parse_line(line_number, line, battery_bank)?;
+        bank_number += 1;
+        part1 += bank_joltage(bank_number, &bank, 2)?;
+        part2 += bank_joltage(bank_number, &bank, 12)?;
+        Ok(())
+    })?;
+
+    Ok((part1, part2))
+}
+
+#[cfg(feature = "embed-input")]
+const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../../../inputs/day03.txt"));
+#[cfg(not(feature = "embed-input"))]
+const EMBEDDED_INPUT: Option<&str> = None;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const EMBEDDED_INPUT: Option<&'static str> = EMBEDDED_INPUT;
+
+    type Model = Vec<Vec<u64>>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        parse_all(input, battery_banks)
+    }
+
+    fn part1(banks: &Self::Model) -> Result<u64> {
+        total_joltage(banks, 2)
+    }
+
+    fn part2(banks: &Self::Model) -> Result<u64> {
+        total_joltage(banks, 12)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    fn reference_part1(input: &str) -> Option<u64> {
+        reference::part1(input)
+    }
+
+    fn reference_part2(input: &str) -> Option<u64> {
+        reference::part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_core::solution_tests!(Day03);
+
+    const EXAMPLE_INPUT: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn test_battery_banks() {
+        let (_, banks) = battery_banks(EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(banks.len(), 4);
+        assert_eq!(banks[0].len(), 15);
+    }
+
+    #[test]
+    fn test_trailing_input() {
+        let err = Day03::parse("987654321111111\n8111111x1111119\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 2, column 8: unexpected \"x1111119\", expected end of input"
+        );
+    }
+
+    #[test]
+    fn test_largest_joltage_2() {
+        let (_, bank) = battery_bank("987654321111111").unwrap();
+        assert_eq!(largest_joltage(&bank, 2), 98);
+
+        let (_, bank) = battery_bank("811111111111119").unwrap();
+        assert_eq!(largest_joltage(&bank, 2), 89);
+
+        let (_, bank) = battery_bank("234234234234278").unwrap();
+        assert_eq!(largest_joltage(&bank, 2), 78);
+
+        let (_, bank) = battery_bank("818181911112111").unwrap();
+        assert_eq!(largest_joltage(&bank, 2), 92);
+    }
+
+    #[test]
+    fn test_largest_joltage_12() {
+        let (_, bank) = battery_bank("987654321111111").unwrap();
+        assert_eq!(largest_joltage(&bank, 12), 987654321111);
+
+        let (_, bank) = battery_bank("811111111111119").unwrap();
+        assert_eq!(largest_joltage(&bank, 12), 811111111119);
+
+        let (_, bank) = battery_bank("234234234234278").unwrap();
+        assert_eq!(largest_joltage(&bank, 12), 434234234278);
+
+        let (_, bank) = battery_bank("818181911112111").unwrap();
+        assert_eq!(largest_joltage(&bank, 12), 888911112111);
+    }
+
+    #[test]
+    fn test_solve_stream() {
+        assert_eq!(
+            solve_stream(EXAMPLE_INPUT.as_bytes()).unwrap(),
+            (357, 3121910778619)
+        );
+
+        let err = solve_stream("987654321111111\n98765\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "solver failed: bank 2 has 5 batteries, need at least 12"
+        );
+    }
+
+    #[test]
+    fn test_short_bank() {
+        let banks = Day03::parse("987654321111111\n98765").unwrap();
+        assert_eq!(Day03::part1(&banks).unwrap(), 98 + 98);
+        assert_eq!(
+            Day03::part2(&banks).unwrap_err().to_string(),
+            "solver failed: bank 2 has 5 batteries, need at least 12"
+        );
+    }
+}
@@ -0,0 +1,64 @@
+//! Exhaustive search over every way to turn on the batteries, to cross-check
+//! the greedy [`crate::largest_joltage`]. Exponential in the bank length.
+
+/// Largest number formed by `n` digits of `bank` in order, or `None` if the
+/// bank is too short.
+fn best(bank: &[u64], n: usize, acc: u64) -> Option<u64> {
+    if n == 0 {
+        return Some(acc);
+    }
+    let (&first, rest) = bank.split_first()?;
+
+    let take = best(rest, n - 1, acc * 10 + first);
+    let skip = best(rest, n, acc);
+    take.max(skip)
+}
+
+fn total(input: &str, n: usize) -> Option<u64> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let bank = line
+                .chars()
+                .map(|c| c.to_digit(10).expect("battery should be a digit") as u64)
+                .collect::<Vec<_>>();
+            best(&bank, n, 0)
+        })
+        .sum()
+}
+
+/// Total joltage turning on two batteries per bank.
+pub fn part1(input: &str) -> Option<u64> {
+    total(input, 2)
+}
+
+/// Total joltage turning on twelve batteries per bank.
+pub fn part2(input: &str) -> Option<u64> {
+    total(input, 12)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::differential;
+
+    use super::*;
+    use crate::{Day03, generate};
+
+    const EXAMPLE_INPUT: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_eq!(part1(EXAMPLE_INPUT), Some(357));
+        assert_eq!(part2(EXAMPLE_INPUT), Some(3121910778619));
+        assert_eq!(part2("98765"), None);
+    }
+
+    #[test]
+    fn test_differential() {
+        differential::assert_agree::<Day03>(100, |rng| {
+            let bank_len = rng.range(12..=16) as usize;
+            generate::input_with(rng, 5, bank_len)
+        });
+    }
+}
@@ -0,0 +1,67 @@
+use aoc_core::Rng;
+
+/// Largest ID generated.
+const MAX_ID: u64 = 1_000_000_000_000;
+
+/// Widest fresh range generated.
+const MAX_SPAN: u64 = 10_000_000_000;
+
+/// Ingredients listed per fresh range.
+const INGREDIENTS_PER_RANGE: usize = 5;
+
+/// `size` possibly overlapping fresh ranges, a blank line, then five times as
+/// many ingredient IDs. About half of the IDs are drawn from inside a fresh
+/// range so both answers are interesting.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    input_with(rng, size, MAX_ID, MAX_SPAN)
+}
+
+/// Like [`input`], with range starts up to `max_id` and ranges covering up to
+/// `max_span + 1` IDs.
+pub fn input_with(rng: &mut Rng, size: usize, max_id: u64, max_span: u64) -> String {
+    let ranges = (0..size.max(1))
+        .map(|_| {
+            let start = rng.range(1..=max_id);
+            start..=start + rng.range(0..=max_span)
+        })
+        .collect::<Vec<_>>();
+
+    let mut out = String::new();
+    for range in &ranges {
+        out.push_str(&format!("{}-{}\n", range.start(), range.end()));
+    }
+    out.push('\n');
+
+    for _ in 0..ranges.len() * INGREDIENTS_PER_RANGE {
+        let id = if rng.chance(1, 2) {
+            let range = rng.choose(&ranges);
+            rng.range(range.clone())
+        } else {
+            rng.range(1..=max_id + max_span)
+        };
+        out.push_str(&format!("{}\n", id));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{Day, Part, Solver};
+
+    use super::*;
+    use crate::Day05;
+
+    #[test]
+    fn test_input() {
+        let input = input(&mut Rng::new(1), 10);
+        let (ranges, ingredients) = input.split_once("\n\n").unwrap();
+        assert_eq!(ranges.lines().count(), 10);
+        assert_eq!(ingredients.lines().count(), 50);
+        assert_eq!(input, super::input(&mut Rng::new(1), 10));
+
+        for seed in 0..20 {
+            let input = super::input(&mut Rng::new(seed), 100);
+            Solver::<Day05>::new().solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
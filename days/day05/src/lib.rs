@@ -0,0 +1,217 @@
+use std::{io::BufRead, mem, ops::RangeInclusive};
+
+use aoc_core::{
+    Result, Rng, Solution,
+    diagnostic::{IResult, parse_all},
+    stream,
+};
+use aoc_parse::{inclusive_range, lines, number, section_pair};
+use aoc_ranges::RangeSet;
+use nom::{Parser, error::context};
+
+pub mod generate;
+pub mod reference;
+
+pub fn fresh_ingredient_range(input: &str) -> IResult<&str, RangeInclusive<u64>> {
+    inclusive_range(input)
+}
+
+pub fn fresh_ingredients(input: &str) -> IResult<&str, Vec<RangeInclusive<u64>>> {
+    lines(fresh_ingredient_range).parse(input)
+}
+
+pub fn ingredient(input: &str) -> IResult<&str, u64> {
+    context("expected an ingredient ID", number).parse(input)
+}
+
+pub fn ingredients(input: &str) -> IResult<&str, Vec<u64>> {
+    lines(ingredient).parse(input)
+}
+
+/// The fresh ID ranges, merged so none overlap, and the available
+/// ingredient IDs.
+#[derive(Debug)]
+pub struct Inventory {
+    fresh_ingredients: RangeSet,
+    ingredients: Vec<u64>,
+}
+
+impl Inventory {
+    pub fn new(fresh_ingredients: Vec<RangeInclusive<u64>>, ingredients: Vec<u64>) -> Self {
+        Self {
+            fresh_ingredients: fresh_ingredients.into_iter().collect(),
+            ingredients,
+        }
+    }
+
+    pub fn fresh_ranges(&self) -> &RangeSet {
+        &self.fresh_ingredients
+    }
+
+    pub fn ingredients(&self) -> &[u64] {
+        &self.ingredients
+    }
+
+    pub fn is_fresh(&self, ingredient: u64) -> bool {
+        self.fresh_ingredients.contains(ingredient)
+    }
+
+    pub fn get_fresh_ingredients(&self) -> Vec<u64> {
+        self.ingredients
+            .iter()
+            .cloned()
+            .filter(|&ing| self.is_fresh(ing))
+            .collect()
+    }
+
+    /// Number of IDs covered by the fresh ranges.
+    pub fn max_fresh_ingredient(&self) -> u64 {
+        self.fresh_ingredients.len()
+    }
+}
+
+/// Parses the fresh ranges and the ingredient IDs, separated by a blank line.
+pub fn inventory(input: &str) -> IResult<&str, Inventory> {
+    section_pair(fresh_ingredients, ingredients)
+        .map(|(fresh_ingredients, ingredients)| Inventory::new(fresh_ingredients, ingredients))
+        .parse(input)
+}
+
+/// Streaming counterpart of [`Day05`]: keeps the fresh ranges, which part 1
+/// needs for every lookup, but counts the ingredients below the blank line as
+/// they are read instead of collecting them.
+pub fn solve_stream(reader: impl BufRead) -> Result<(usize, u64)> {
+    let mut ranges = Vec::new();
+    let mut inventory: Option<Inventory> = None;
+    let mut fresh = 0;
+
+    stream::for_each_line(reader, |line_number, line| {
+        if let Some(inventory) = &inventory {
+            if !line.is_empty() {
+                let id = stream::parse_line(line_number, line, ingredient)?;
+                fresh += usize::from(inventory.is_fresh(id));
+            }
+        } else if line.is_empty() {
+            inventory = Some(Inventory::new(mem::take(&mut ranges), Vec::new()));
+        } else {
+            ranges.push(stream::parse_line(
+                line_number,
+                line,
+                fresh_ingredient_range,
+            )?);
+        }
+        Ok(())
+    })?;
+
+    let inventory = inventory.unwrap_or_else(|| Inventory::new(ranges, Vec::new()));
+    Ok((fresh, inventory.max_fresh_ingredient()))
+}
+
+#[cfg(feature = "embed-input")]
+const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../../../inputs/day05.txt"));
+#[cfg(not(feature = "embed-input"))]
+const EMBEDDED_INPUT: Option<&str> = None;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const EMBEDDED_INPUT: Option<&'static str> = EMBEDDED_INPUT;
+
+    type Model = Inventory;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        parse_all(input, inventory)
+    }
+
+    fn part1(inventory: &Inventory) -> Result<usize> {
+        Ok(inventory.get_fresh_ingredients().len())
+    }
+
+    fn part2(inventory: &Inventory) -> Result<u64> {
+        Ok(inventory.max_fresh_ingredient())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    fn reference_part1(input: &str) -> Option<usize> {
+        Some(reference::part1(input))
+    }
+
+    fn reference_part2(input: &str) -> Option<u64> {
+        Some(reference::part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_core::solution_tests!(Day05);
+
+    const EXAMPLE_INPUT: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn test_fresh_ingredient_range() {
+        let input = "3-5";
+        let (_, range) = fresh_ingredient_range(input).unwrap();
+        assert_eq!(range, 3..=5);
+    }
+
+    #[test]
+    fn test_fresh_ingredients() {
+        let input = "3-5\n10-14\n16-20\n12-18";
+        let (_, ranges) = fresh_ingredients(input).unwrap();
+        assert_eq!(ranges, vec![3..=5, 10..=14, 16..=20, 12..=18]);
+    }
+
+    #[test]
+    fn test_ingredients() {
+        let input = "1\n5\n8\n11\n17\n32";
+        let (_, ingredients) = ingredients(input).unwrap();
+        assert_eq!(ingredients, vec![1, 5, 8, 11, 17, 32]);
+    }
+
+    #[test]
+    fn test_trailing_input() {
+        let err = Day05::parse("3-5\n10-14\n\n1\n5\nfive\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 6, column 1: expected an ingredient ID"
+        );
+    }
+
+    #[test]
+    fn test_reversed_range() {
+        let err = Day05::parse("3-5\n20-10\n\n1\n15\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid value at line 2, column 1: range 20-10 ends before it starts"
+        );
+
+        let err = solve_stream("3-5\n20-10\n\n1\n".as_bytes()).unwrap_err();
+        assert_eq!(err.position(), Some(aoc_core::Position::new(2, 1)));
+    }
+
+    #[test]
+    fn test_solve_stream() {
+        assert_eq!(solve_stream(EXAMPLE_INPUT.as_bytes()).unwrap(), (3, 14));
+
+        let err = solve_stream("3-5\n\n1\nfive\n".as_bytes()).unwrap_err();
+        assert_eq!(err.position(), Some(aoc_core::Position::new(4, 1)));
+    }
+
+    #[test]
+    fn test_inventory() {
+        let (_, inventory) = inventory(EXAMPLE_INPUT).unwrap();
+        assert_eq!(
+            inventory.fresh_ranges().iter().collect::<Vec<_>>(),
+            vec![3..=5, 10..=20]
+        );
+        assert_eq!(inventory.ingredients, vec![1, 5, 8, 11, 17, 32]);
+    }
+}
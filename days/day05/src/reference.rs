@@ -0,0 +1,68 @@
+//! Per-ID membership over the ranges as written, to cross-check the merging
+//! in [`aoc_ranges::RangeSet`]. Linear in the size of the ID space.
+
+fn parse(input: &str) -> (Vec<(u64, u64)>, Vec<u64>) {
+    let (ranges, ids) = input
+        .split_once("\n\n")
+        .expect("ranges and IDs should be separated by a blank line");
+
+    let ranges = ranges
+        .lines()
+        .map(|line| {
+            let (start, end) = line.split_once('-').expect("range should have a '-'");
+            (
+                start.parse().expect("start should be a number"),
+                end.parse().expect("end should be a number"),
+            )
+        })
+        .collect();
+    let ids = ids
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.parse().expect("ID should be a number"))
+        .collect();
+
+    (ranges, ids)
+}
+
+fn is_fresh(ranges: &[(u64, u64)], id: u64) -> bool {
+    ranges.iter().any(|&(start, end)| start <= id && id <= end)
+}
+
+/// Available ingredients inside some fresh range.
+pub fn part1(input: &str) -> usize {
+    let (ranges, ids) = parse(input);
+    ids.into_iter().filter(|&id| is_fresh(&ranges, id)).count()
+}
+
+/// IDs inside some fresh range, counted one at a time.
+pub fn part2(input: &str) -> u64 {
+    let (ranges, _) = parse(input);
+    let lowest = ranges.iter().map(|&(start, _)| start).min().unwrap_or(0);
+    let highest = ranges.iter().map(|&(_, end)| end).max().unwrap_or(0);
+
+    (lowest..=highest)
+        .filter(|&id| is_fresh(&ranges, id))
+        .count() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::differential;
+
+    use super::*;
+    use crate::{Day05, generate};
+
+    const EXAMPLE_INPUT: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_eq!(part1(EXAMPLE_INPUT), 3);
+        assert_eq!(part2(EXAMPLE_INPUT), 14);
+    }
+
+    #[test]
+    fn test_differential() {
+        differential::assert_agree::<Day05>(200, |rng| generate::input_with(rng, 8, 1_000, 50));
+    }
+}
@@ -0,0 +1,62 @@
+use aoc_core::Rng;
+
+/// Most digits in a generated number.
+const MAX_DIGITS: u32 = 4;
+
+/// A worksheet of `size` problems side by side. Each problem is a column of
+/// 3 or 4 numbers padded with spaces to the problem's width, all left or all
+/// right aligned as in the real input, with its operator under the leftmost
+/// column. Problems are separated by a column of spaces and every line has
+/// the same length, so the worksheet reads the same in both directions.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let rows = rng.range(3..=4) as usize;
+    let mut lines = vec![String::new(); rows + 1];
+
+    for problem in 0..size.max(1) {
+        let numbers = (0..rows)
+            .map(|_| {
+                let digits = rng.range(1..=MAX_DIGITS as u64) as u32;
+                rng.range(10u64.pow(digits - 1)..=10u64.pow(digits) - 1)
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+        let width = numbers.iter().map(String::len).max().unwrap_or(1);
+        let operator = if rng.chance(1, 2) { '*' } else { '+' };
+        let left_aligned = rng.chance(1, 2);
+
+        if problem > 0 {
+            lines.iter_mut().for_each(|line| line.push(' '));
+        }
+        for (line, number) in lines.iter_mut().zip(&numbers) {
+            if left_aligned {
+                line.push_str(&format!("{:<width$}", number));
+            } else {
+                line.push_str(&format!("{:>width$}", number));
+            }
+        }
+        lines[rows].push_str(&format!("{:<width$}", operator));
+    }
+
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{Day, Part, Solver};
+
+    use super::*;
+    use crate::Day06;
+
+    #[test]
+    fn test_input() {
+        let input = input(&mut Rng::new(1), 10);
+        let width = input.lines().next().unwrap().len();
+        assert!(input.lines().all(|line| line.len() == width));
+        assert_eq!(input, super::input(&mut Rng::new(1), 10));
+
+        for seed in 0..20 {
+            let input = super::input(&mut Rng::new(seed), 100);
+            Solver::<Day06>::new().solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
@@ -0,0 +1,332 @@
+use aoc_core::{
+    Error, Normalize, Position, Result, Rng, Solution,
+    diagnostic::{IResult, parse_all},
+};
+use aoc_grid::Grid;
+use aoc_parse::{spaced, spaced_list};
+use nom::{
+    Parser,
+    character::complete::{digit1, line_ending, multispace0, newline, one_of, space1},
+    combinator::opt,
+    error::context,
+    multi::{many1, separated_list1},
+    sequence::terminated,
+};
+
+pub mod generate;
+pub mod reference;
+
+pub fn number(input: &str) -> IResult<&str, u64> {
+    context("expected a number", aoc_parse::number).parse(input)
+}
+
+/// A number read down one column. A shorter number in a middle row can leave
+/// a blank between its digits, which still belong to the same number.
+pub fn column_number(input: &str) -> IResult<&str, u64> {
+    spaced(context(
+        "expected a number",
+        separated_list1(space1, digit1).map_res(|digits: Vec<&str>| digits.concat().parse::<u64>()),
+    ))
+    .parse(input)
+}
+
+pub fn numbers(input: &str) -> IResult<&str, Vec<u64>> {
+    many1(terminated(column_number, opt(newline))).parse(input)
+}
+
+pub fn number_row(input: &str) -> IResult<&str, Vec<u64>> {
+    spaced_list(number).parse(input)
+}
+
+pub fn number_rows(input: &str) -> IResult<&str, Vec<Vec<u64>>> {
+    many1(terminated(number_row, line_ending)).parse(input)
+}
+
+#[derive(PartialEq, Debug)]
+pub enum Operator {
+    Mult,
+    Add,
+}
+
+impl Operator {
+    pub fn apply(&self, a: u64, b: u64) -> u64 {
+        match self {
+            Operator::Mult => a * b,
+            Operator::Add => a + b,
+        }
+    }
+}
+
+pub fn operator(input: &str) -> IResult<&str, Operator> {
+    let (input, char) = context("expected '*' or '+'", one_of("*+")).parse(input)?;
+
+    let operator = match char {
+        '*' => Operator::Mult,
+        '+' => Operator::Add,
+        _ => unreachable!(),
+    };
+
+    Ok((input, operator))
+}
+
+pub fn operator_list(input: &str) -> IResult<&str, Vec<Operator>> {
+    spaced_list(operator).parse(input)
+}
+
+/// A problem read column by column: its operands top to bottom, then its
+/// operator.
+#[derive(Debug)]
+pub struct Expression {
+    pub operator: Operator,
+    pub operands: Vec<u64>,
+}
+
+pub fn expression(input: &str) -> IResult<&str, Expression> {
+    let (input, operands) = numbers(input)?;
+    let (input, operator) = operator(input)?;
+
+    Ok((input, Expression { operator, operands }))
+}
+
+pub fn expressions(input: &str) -> IResult<&str, Vec<Expression>> {
+    many1(terminated(expression, multispace0)).parse(input)
+}
+
+/// The worksheet read row by row: rows of numbers, then one operator per
+/// problem.
+#[derive(PartialEq, Debug)]
+pub struct ParsedInput {
+    pub number_rows: Vec<Vec<u64>>,
+    pub operators: Vec<Operator>,
+}
+
+pub fn parsed_input(input: &str) -> IResult<&str, ParsedInput> {
+    let (input, (number_rows, operators)) = (number_rows, operator_list).parse(input)?;
+
+    Ok((
+        input,
+        ParsedInput {
+            number_rows,
+            operators,
+        },
+    ))
+}
+
+/// Maps a position in the anticlockwise-rotated worksheet back to the
+/// original input.
+fn unrotated_position(input: &str, rotated: Position) -> Position {
+    let width = input.lines().map(|line| line.len()).max().unwrap_or(0);
+    Position::new(rotated.column, (width + 1).saturating_sub(rotated.line))
+}
+
+/// Turns the worksheet a quarter anticlockwise, so its columns read right to
+/// left become rows read top to bottom.
+fn rotate_anticlockwise(input: &str) -> Result<String> {
+    let sheet = Grid::parse_padded(input, ' ', |_, ch| Ok(ch))?;
+    Ok(sheet.rotate_anticlockwise().to_string())
+}
+
+/// The worksheet in both readings: row by row for part 1, and column by
+/// column, right to left, for part 2.
+#[derive(Debug)]
+pub struct Worksheet {
+    pub parsed: ParsedInput,
+    pub expressions: Vec<Expression>,
+}
+
+#[cfg(feature = "embed-input")]
+const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../../../inputs/day06.txt"));
+#[cfg(not(feature = "embed-input"))]
+const EMBEDDED_INPUT: Option<&str> = None;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const EMBEDDED_INPUT: Option<&'static str> = EMBEDDED_INPUT;
+    // Trailing spaces keep the columns aligned for the right-to-left reading
+    const NORMALIZE: Normalize = Normalize {
+        trim_line_ends: false,
+        ..Normalize::DEFAULT
+    };
+
+    type Model = Worksheet;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        let parsed = parse_all(input, parsed_input)?;
+
+        let rotated = &rotate_anticlockwise(input)?;
+        let expressions = parse_all(rotated, expressions).map_err(|err| {
+            let position = err.position().expect("parse errors have a position");
+            Error::parse(
+                unrotated_position(input, position),
+                format!("{} while reading columns right to left", err.message()),
+            )
+        })?;
+
+        Ok(Worksheet {
+            parsed,
+            expressions,
+        })
+    }
+
+    fn part1(worksheet: &Worksheet) -> Result<u64> {
+        let parsed = &worksheet.parsed;
+
+        (0..parsed.operators.len())
+            .map(|idx| {
+                let operator = &parsed.operators[idx];
+
+                parsed
+                    .number_rows
+                    .iter()
+                    .enumerate()
+                    .map(|(row, number_row)| {
+                        number_row.get(idx).copied().ok_or_else(|| {
+                            Error::solve(format!(
+                                "row {} has {} numbers but there are {} operators",
+                                row + 1,
+                                number_row.len(),
+                                parsed.operators.len()
+                            ))
+                        })
+                    })
+                    .reduce(|a, b| Ok(operator.apply(a?, b?)))
+                    .expect("there should be at least one number row")
+            })
+            .sum()
+    }
+
+    fn part2(worksheet: &Worksheet) -> Result<u64> {
+        Ok(worksheet
+            .expressions
+            .iter()
+            .map(|expr| {
+                expr.operands
+                    .iter()
+                    .copied()
+                    .reduce(|a, b| expr.operator.apply(a, b))
+                    .expect("there should be at least one operand")
+            })
+            .sum())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    fn reference_part1(input: &str) -> Option<u64> {
+        Some(reference::part1(input))
+    }
+
+    fn reference_part2(input: &str) -> Option<u64> {
+        Some(reference::part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_core::solution_tests!(Day06);
+
+    const EXAMPLE_INPUT: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn test_number_row() {
+        let (_, row) = number_row("123 328  51 64 ").unwrap();
+        assert_eq!(row, vec![123, 328, 51, 64]);
+
+        let (_, row) = number_row(" 45 64  387 23 ").unwrap();
+        assert_eq!(row, vec![45, 64, 387, 23]);
+    }
+
+    #[test]
+    fn test_number_rows() {
+        let (_, rows) = number_rows("123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n").unwrap();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0], vec![123, 328, 51, 64]);
+        assert_eq!(rows[1], vec![45, 64, 387, 23]);
+        assert_eq!(rows[2], vec![6, 98, 215, 314]);
+    }
+
+    #[test]
+    fn test_parsed_input() {
+        let (_, parsed) = parsed_input(EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(parsed.number_rows.len(), 3);
+        assert_eq!(parsed.operators.len(), 4);
+
+        assert_eq!(
+            parsed,
+            ParsedInput {
+                number_rows: vec![
+                    vec![123, 328, 51, 64],
+                    vec![45, 64, 387, 23],
+                    vec![6, 98, 215, 314],
+                ],
+                operators: vec![Operator::Mult, Operator::Add, Operator::Mult, Operator::Add,],
+            }
+        )
+    }
+
+    #[test]
+    fn test_trailing_input() {
+        let err = Day06::parse("1 2\n3 4\n* -\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 3, column 3: expected '*' or '+'"
+        );
+    }
+
+    #[test]
+    fn test_ragged_rows() {
+        let worksheet = Day06::parse("1 2\n3\n* +").unwrap();
+        assert_eq!(
+            Day06::part1(&worksheet).unwrap_err().to_string(),
+            "solver failed: row 2 has 1 numbers but there are 2 operators"
+        );
+    }
+
+    #[test]
+    fn test_interior_blank() {
+        // The shorter middle number leaves a blank inside the second column
+        let worksheet = Day06::parse("123\n4  \n567\n*  ").unwrap();
+        assert_eq!(Day06::part2(&worksheet).unwrap(), 145 * 26 * 37);
+    }
+
+    #[test]
+    fn test_unrotated_position() {
+        let input = "12 \n 3 \n*  ";
+        assert_eq!(
+            unrotated_position(input, Position::new(1, 1)),
+            Position::new(1, 3)
+        );
+        assert_eq!(
+            unrotated_position(input, Position::new(3, 2)),
+            Position::new(2, 1)
+        );
+    }
+
+    #[test]
+    fn test_expression() {
+        let (_, expr) = expression("123\n456\n789*").unwrap();
+        assert_eq!(expr.operator, Operator::Mult);
+        assert_eq!(expr.operands, vec![123, 456, 789]);
+
+        let (_, expr) = expression("31 2\n 4  +").unwrap();
+        assert_eq!(expr.operands, vec![312, 4]);
+    }
+
+    #[test]
+    fn test_expressions() {
+        let example_input = rotate_anticlockwise(EXAMPLE_INPUT).unwrap();
+        let (_, exprs) = expressions(&example_input).unwrap();
+        assert_eq!(exprs.len(), 4);
+
+        assert_eq!(exprs[0].operator, Operator::Add);
+        assert_eq!(exprs[0].operands, vec![4, 431, 623]);
+    }
+}
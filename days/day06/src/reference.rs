@@ -0,0 +1,129 @@
+//! Reads the worksheet straight off the character grid, to cross-check the
+//! nom parsers and the rotation used for part 2.
+
+/// A worksheet as rows of bytes: the number rows, the operator row, and the
+/// `from..to` column span of every problem.
+struct Sheet<'a> {
+    numbers: Vec<&'a [u8]>,
+    operators: &'a [u8],
+    spans: Vec<(usize, usize)>,
+}
+
+fn problems(input: &str) -> Sheet<'_> {
+    let lines = input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(str::as_bytes)
+        .collect::<Vec<_>>();
+    let (&operators, numbers) = lines.split_last().expect("worksheet should not be empty");
+
+    let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+    let blank = |col: usize| {
+        lines
+            .iter()
+            .all(|line| line.get(col).is_none_or(|&c| c == b' '))
+    };
+
+    let mut spans = Vec::new();
+    let mut start = None;
+    for col in 0..=width {
+        match (start, col < width && !blank(col)) {
+            (None, true) => start = Some(col),
+            (Some(from), false) => {
+                spans.push((from, col));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    Sheet {
+        numbers: numbers.to_vec(),
+        operators,
+        spans,
+    }
+}
+
+fn cell(line: &[u8], from: usize, to: usize) -> &str {
+    let from = from.min(line.len());
+    let to = to.min(line.len());
+    std::str::from_utf8(&line[from..to])
+        .expect("worksheet should be ASCII")
+        .trim()
+}
+
+fn apply(operator: &str, operands: impl Iterator<Item = u64>) -> u64 {
+    match operator {
+        "*" => operands.product(),
+        "+" => operands.sum(),
+        other => panic!("unknown operator {:?}", other),
+    }
+}
+
+/// Grand total reading each problem's numbers row by row.
+pub fn part1(input: &str) -> u64 {
+    let Sheet {
+        numbers,
+        operators,
+        spans,
+    } = problems(input);
+
+    spans
+        .iter()
+        .map(|&(from, to)| {
+            let operands = numbers.iter().map(|line| {
+                cell(line, from, to)
+                    .parse::<u64>()
+                    .expect("cell should be a number")
+            });
+            apply(cell(operators, from, to), operands)
+        })
+        .sum()
+}
+
+/// Grand total reading each problem's numbers column by column, the digits
+/// of each number top to bottom.
+pub fn part2(input: &str) -> u64 {
+    let Sheet {
+        numbers,
+        operators,
+        spans,
+    } = problems(input);
+
+    spans
+        .iter()
+        .map(|&(from, to)| {
+            let operands = (from..to).rev().filter_map(|col| {
+                let digits = numbers
+                    .iter()
+                    .filter_map(|line| line.get(col))
+                    .filter(|c| c.is_ascii_digit())
+                    .map(|&c| char::from(c))
+                    .collect::<String>();
+                digits.parse::<u64>().ok()
+            });
+            apply(cell(operators, from, to), operands)
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::differential;
+
+    use super::*;
+    use crate::{Day06, generate};
+
+    const EXAMPLE_INPUT: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_eq!(part1(EXAMPLE_INPUT), 4277556);
+        assert_eq!(part2(EXAMPLE_INPUT), 3263827);
+    }
+
+    #[test]
+    fn test_differential() {
+        differential::assert_agree::<Day06>(200, |rng| generate::input(rng, 10));
+    }
+}
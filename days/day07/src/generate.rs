@@ -0,0 +1,45 @@
+use aoc_core::Rng;
+
+/// A manifold `size` rows tall and about as wide, with the start marker in
+/// the middle of the top row. As in the real input, splitters only sit on
+/// every other row, each with a 30% chance, which keeps the timeline count
+/// well within `usize`.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let height = size.max(2);
+    let width = height | 1;
+
+    (0..height)
+        .map(|row| {
+            let mut line = (0..width)
+                .map(|col| match (row, col) {
+                    (0, col) if col == width / 2 => 'S',
+                    (row, _) if row > 0 && row % 2 == 0 && rng.chance(3, 10) => '^',
+                    _ => '.',
+                })
+                .collect::<String>();
+            line.push('\n');
+            line
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{Day, Part, Solver};
+
+    use super::*;
+    use crate::Day07;
+
+    #[test]
+    fn test_input() {
+        let input = input(&mut Rng::new(1), 20);
+        assert_eq!(input.lines().count(), 20);
+        assert_eq!(input.matches('S').count(), 1);
+        assert_eq!(input, super::input(&mut Rng::new(1), 20));
+
+        for seed in 0..20 {
+            let input = super::input(&mut Rng::new(seed), 142);
+            Solver::<Day07>::new().solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
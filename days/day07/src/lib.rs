@@ -0,0 +1,249 @@
+use aoc_core::{Error, Position, Result, Rng, Solution};
+use aoc_grid::{Grid, Point};
+
+pub mod generate;
+pub mod reference;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Component {
+    Empty,
+    Splitter,
+}
+
+/// Reads one cell of the manifold, keeping the start marker as a character so
+/// it can be located before the grid is turned into components.
+pub fn manifold_cell(ch: char) -> std::result::Result<char, String> {
+    match ch {
+        '.' | '^' | 'S' => Ok(ch),
+        _ => Err(format!(
+            "unknown character '{}', expected '.', '^' or 'S'",
+            ch
+        )),
+    }
+}
+
+/// The manifold grid and where the beam enters it.
+#[derive(Debug)]
+pub struct TachyonManifold {
+    start_position: Point,
+    grid: Grid<Component>,
+}
+
+/// A beam in one column, standing for `stacked_count` timelines that ended up
+/// in the same place.
+pub struct TachyonBeam {
+    pub column_position: usize,
+    pub stacked_count: usize,
+}
+
+/// The beams leaving the bottom of the manifold, with how many times they
+/// were split on the way down.
+pub struct TachyonBeams {
+    pub beams: Vec<TachyonBeam>,
+
+    pub number_of_splits: usize,
+    pub number_of_timelines: usize,
+}
+
+impl TachyonManifold {
+    pub fn start_position(&self) -> Point {
+        self.start_position
+    }
+
+    pub fn grid(&self) -> &Grid<Component> {
+        &self.grid
+    }
+
+    pub fn height(&self) -> usize {
+        self.grid.height()
+    }
+
+    pub fn width(&self) -> usize {
+        self.grid.width()
+    }
+
+    /// Sends a beam down from the start marker through every splitter.
+    pub fn beams(&self) -> TachyonBeams {
+        let mut beams = TachyonBeams {
+            beams: vec![TachyonBeam {
+                column_position: self.start_position.column,
+                stacked_count: 1,
+            }],
+            number_of_splits: 0,
+            number_of_timelines: 0,
+        };
+
+        beams = self
+            .grid
+            .rows()
+            .skip(self.start_position.row)
+            .fold(beams, |mut acc, row| {
+                let mut new_beams = acc.beams.into_iter().fold(vec![], |mut new_beams, beam| {
+                    let column_position = beam.column_position;
+
+                    match row.get(column_position) {
+                        Some(Component::Splitter) => {
+                            acc.number_of_splits += 1;
+
+                            // A beam split past the left edge leaves the manifold, just
+                            // like one past the right edge is dropped by `row.get` above
+                            if let Some(left) = column_position.checked_sub(1) {
+                                new_beams.push(TachyonBeam {
+                                    column_position: left,
+                                    stacked_count: beam.stacked_count,
+                                });
+                            }
+                            new_beams.push(TachyonBeam {
+                                column_position: column_position + 1,
+                                stacked_count: beam.stacked_count,
+                            });
+                        }
+                        Some(Component::Empty) => {
+                            new_beams.push(beam);
+                        }
+                        None => {}
+                    }
+                    new_beams
+                });
+                new_beams.sort_by_key(|beam| beam.column_position);
+                acc.beams = new_beams.into_iter().fold(
+                    Vec::<TachyonBeam>::new(),
+                    |mut deduped_beams, beam| {
+                        if let Some(last_beam) = deduped_beams.last_mut()
+                            && last_beam.column_position == beam.column_position
+                        {
+                            last_beam.stacked_count += beam.stacked_count;
+                            return deduped_beams;
+                        }
+                        deduped_beams.push(beam);
+                        deduped_beams
+                    },
+                );
+                acc
+            });
+
+        beams.number_of_timelines = beams.beams.iter().map(|beam| beam.stacked_count).sum();
+
+        beams
+    }
+}
+
+/// Parses the manifold grid, which must have exactly one start marker.
+pub fn tachyon_manifold(input: &str) -> Result<TachyonManifold> {
+    let cells = Grid::parse(input, |_, ch| manifold_cell(ch))?;
+
+    let mut starts = cells.positions(|&ch| ch == 'S');
+    let start_position = starts.next().ok_or_else(|| {
+        Error::parse(
+            Position::locate(input, input.len()),
+            "no start position 'S' in manifold",
+        )
+    })?;
+    if let Some(second) = starts.next() {
+        return Err(Error::invalid_value(
+            second.position(),
+            format!(
+                "second start position, the first is at {}",
+                start_position.position()
+            ),
+        ));
+    }
+
+    Ok(TachyonManifold {
+        start_position,
+        grid: cells.map(|&ch| match ch {
+            '^' => Component::Splitter,
+            _ => Component::Empty,
+        }),
+    })
+}
+
+#[cfg(feature = "embed-input")]
+const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../../../inputs/day07.txt"));
+#[cfg(not(feature = "embed-input"))]
+const EMBEDDED_INPUT: Option<&str> = None;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const EMBEDDED_INPUT: Option<&'static str> = EMBEDDED_INPUT;
+
+    type Model = TachyonManifold;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        tachyon_manifold(input)
+    }
+
+    fn part1(manifold: &TachyonManifold) -> Result<usize> {
+        Ok(manifold.beams().number_of_splits)
+    }
+
+    fn part2(manifold: &TachyonManifold) -> Result<usize> {
+        Ok(manifold.beams().number_of_timelines)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    fn reference_part1(input: &str) -> Option<usize> {
+        Some(reference::part1(input))
+    }
+
+    fn reference_part2(input: &str) -> Option<usize> {
+        Some(reference::part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_core::solution_tests!(Day07);
+
+    const EXAMPLE_INPUT: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn test_manifold_cell() {
+        assert_eq!(manifold_cell('S'), Ok('S'));
+        assert_eq!(manifold_cell('^'), Ok('^'));
+        assert!(manifold_cell('x').is_err());
+    }
+
+    #[test]
+    fn test_tachyon_manifold() {
+        let manifold = tachyon_manifold(EXAMPLE_INPUT.trim()).unwrap();
+        assert_eq!(manifold.start_position(), Point::new(0, 7));
+        assert_eq!(manifold.grid()[Point::new(2, 7)], Component::Splitter);
+        assert_eq!(manifold.height(), 16);
+        assert_eq!(manifold.width(), 15);
+    }
+
+    #[test]
+    fn test_invalid_manifold() {
+        let err = tachyon_manifold("..S..\n..^x.").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid value at line 2, column 4: unknown character 'x', expected '.', '^' or 'S'"
+        );
+
+        let err = tachyon_manifold("..S..\n.S...").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid value at line 2, column 2: second start position, the first is at line 1, column 3"
+        );
+
+        let err = tachyon_manifold(".....\n..^..").unwrap_err();
+        assert_eq!(err.position(), Some(Position::new(2, 6)));
+    }
+
+    #[test]
+    fn test_split_at_edge() {
+        let manifold = tachyon_manifold("S.\n^.\n..").unwrap();
+        assert_eq!(Day07::part1(&manifold).unwrap(), 1);
+        assert_eq!(Day07::part2(&manifold).unwrap(), 1);
+    }
+}
@@ -0,0 +1,87 @@
+//! Follows every timeline on its own, to cross-check the merged beams in
+//! [`crate::TachyonManifold::beams`]. Exponential in the number of splits.
+
+use std::collections::HashSet;
+
+struct Grid<'a> {
+    rows: Vec<&'a [u8]>,
+}
+
+impl Grid<'_> {
+    /// Follows one timeline down from `(row, col)`, recording every splitter
+    /// it hits, and returns how many timelines leave through the bottom. A
+    /// beam that leaves through a side is lost.
+    fn walk(&self, row: usize, col: usize, splitters: &mut HashSet<(usize, usize)>) -> u64 {
+        let Some(line) = self.rows.get(row) else {
+            return 1;
+        };
+
+        match line.get(col) {
+            None => 0,
+            Some(b'^') => {
+                splitters.insert((row, col));
+                let left = match col.checked_sub(1) {
+                    Some(left) => self.walk(row + 1, left, splitters),
+                    None => 0,
+                };
+                left + self.walk(row + 1, col + 1, splitters)
+            }
+            Some(_) => self.walk(row + 1, col, splitters),
+        }
+    }
+}
+
+/// Splitters hit and timelines through the manifold.
+fn trace(input: &str) -> (usize, u64) {
+    let grid = Grid {
+        rows: input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(str::as_bytes)
+            .collect(),
+    };
+    let (row, col) = grid
+        .rows
+        .iter()
+        .enumerate()
+        .find_map(|(row, line)| Some((row, line.iter().position(|&c| c == b'S')?)))
+        .expect("manifold should have a start");
+
+    let mut splitters = HashSet::new();
+    let timelines = grid.walk(row, col, &mut splitters);
+    (splitters.len(), timelines)
+}
+
+/// Splitters reached by any beam.
+pub fn part1(input: &str) -> usize {
+    trace(input).0
+}
+
+/// Timelines that make it out of the bottom of the manifold.
+pub fn part2(input: &str) -> usize {
+    trace(input).1 as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::differential;
+
+    use super::*;
+    use crate::{Day07, generate};
+
+    const EXAMPLE_INPUT: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_eq!(part1(EXAMPLE_INPUT), 21);
+        assert_eq!(part2(EXAMPLE_INPUT), 40);
+    }
+
+    #[test]
+    fn test_differential() {
+        differential::assert_agree::<Day07>(300, |rng| {
+            let size = rng.range(2..=14) as usize;
+            generate::input(rng, size)
+        });
+    }
+}